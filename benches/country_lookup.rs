@@ -1,4 +1,4 @@
-use country_emoji::{code, flag, name};
+use country_emoji::{code, flag, name, suggest};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 fn benchmark_exact_matches(c: &mut Criterion) {
@@ -31,7 +31,7 @@ fn benchmark_fuzzy_matches(c: &mut Criterion) {
         "Russia",              // Should match Russian Federation
         "Vatican",             // Should match Holy See (Vatican City State)
         "UAE",                 // Should match United Arab Emirates
-        "Congo",               // Should match Democratic Republic of Congo
+        "Congo",               // Ambiguous - should return None
         "Korea",               // Ambiguous - should return None
         "Guinea",              // Should match Guinea exactly
         "Virgin Islands",      // Ambiguous without qualifier
@@ -208,6 +208,20 @@ fn benchmark_diacritic_handling(c: &mut Criterion) {
     });
 }
 
+fn benchmark_suggest(c: &mut Criterion) {
+    let keystrokes = vec![
+        "u", "un", "uni", "unit", "s", "st", "st v", "st vin", "c", "cô",
+    ];
+
+    c.bench_function("suggest", |b| {
+        b.iter(|| {
+            for prefix in &keystrokes {
+                black_box(suggest(black_box(prefix), 8));
+            }
+        })
+    });
+}
+
 criterion_group!(
     benches,
     benchmark_exact_matches,
@@ -218,7 +232,8 @@ criterion_group!(
     benchmark_government_patterns,
     benchmark_flag_operations,
     benchmark_edge_cases,
-    benchmark_diacritic_handling
+    benchmark_diacritic_handling,
+    benchmark_suggest
 );
 
 criterion_main!(benches);
@@ -1,7 +1,9 @@
-use crate::Country;
+use crate::{CodeStatus, Country};
 use once_cell::sync::Lazy;
 
 pub(crate) const COUNTRY_CODE_INDEX_SIZE: usize = 26 * 26;
+pub(crate) const ALPHA3_CODE_INDEX_SIZE: usize = 26 * 26 * 26;
+pub(crate) const NUMERIC_CODE_INDEX_SIZE: usize = 1000;
 
 pub(crate) fn country_code_index_from_bytes(bytes: &[u8]) -> Option<usize> {
     if bytes.len() != 2 {
@@ -20,429 +22,507 @@ pub(crate) fn country_code_index_from_bytes(bytes: &[u8]) -> Option<usize> {
     Some(((first - b'A') as usize) * 26 + (second - b'A') as usize)
 }
 
+pub(crate) fn alpha3_code_index_from_bytes(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() != 3 {
+        return None;
+    }
+
+    bytes.iter().try_fold(0, |index, byte| {
+        let letter = byte.to_ascii_uppercase();
+        if letter.is_ascii_uppercase() {
+            Some(index * 26 + (letter - b'A') as usize)
+        } else {
+            None
+        }
+    })
+}
+
+pub(crate) fn numeric_code_index_from_bytes(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() != 3 {
+        return None;
+    }
+
+    // Numeric codes are always zero-padded to three digits, so "076" maps to 76.
+    bytes.iter().try_fold(0, |index, byte| {
+        if byte.is_ascii_digit() {
+            Some(index * 10 + (byte - b'0') as usize)
+        } else {
+            None
+        }
+    })
+}
+
 // TODO UPDATE LIST
-pub(crate) static COUNTRIES: Lazy<Vec<Country>> = Lazy::new(|| {
-    vec![
-        ("AC", &["Ascension Island", "Ascension Islander"]),
-        ("AD", &["Andorra", "Andorran"]),
-        ("AE", &["United Arab Emirates", "UAE", "Emirati"]),
-        ("AF", &["Afghanistan", "Afghan"]),
-        ("AG", &["Antigua & Barbuda", "Antiguan", "Barbudan"]),
-        ("AI", &["Anguilla", "Anguillian"]),
-        ("AL", &["Albania", "Albanian"]),
-        ("AM", &["Armenia", "Armenian"]),
-        ("AN", &["Netherlands Antilles"]), // Dissolved 2010, kept for legacy compatibility
-        ("AO", &["Angola", "Angolan"]),
-        ("AQ", &["Antarctica", "Antarctican"]),
-        ("AR", &["Argentina", "Argentine"]),
-        ("AS", &["American Samoa", "American Samoan"]),
-        ("AT", &["Austria", "Austrian"]),
-        ("AU", &["Australia", "Australian"]),
-        ("AW", &["Aruba", "Aruban"]),
-        ("AX", &["Åland Islands", "Ålandish"]),
-        ("AZ", &["Azerbaijan", "Azerbaijani"]),
-        ("BA", &["Bosnia & Herzegovina", "Bosnian", "Herzegovinian"]),
-        ("BB", &["Barbados", "Barbadian"]),
-        ("BD", &["Bangladesh", "Bangladeshi"]),
-        ("BE", &["Belgium", "Belgian"]),
-        ("BF", &["Burkina Faso", "Burkinabe"]),
-        ("BG", &["Bulgaria", "Bulgarian"]),
-        ("BH", &["Bahrain", "Bahraini"]),
-        ("BI", &["Burundi", "Burundian"]),
-        ("BJ", &["Benin", "Beninese"]),
-        ("BL", &["Saint Barthélemy", "Barthélemois"]),
-        ("BM", &["Bermuda", "Bermudian"]),
-        ("BN", &["Brunei", "Brunei Darussalam", "Bruneian"]),
-        (
-            "BO",
-            &["Bolivia", "Bolivian", "Plurinational State of Bolivia"],
-        ),
-        (
-            "BQ",
-            &[
-                "Caribbean Netherlands",
-                "Bonaire, Sint Eustatius and Saba",
-                "BES Islands",
-                "Caribbean Dutch",
-            ],
-        ),
-        ("BR", &["Brazil", "Brazilian"]),
-        ("BS", &["Bahamas", "Bahamian"]),
-        ("BT", &["Bhutan", "Bhutanese"]),
-        ("BV", &["Bouvet Island", "Bouvet Islander"]),
-        ("BW", &["Botswana", "Motswana"]),
-        ("BY", &["Belarus", "Belarusian"]),
-        ("BZ", &["Belize", "Belizean"]),
-        ("CA", &["Canada", "Canadian"]),
-        (
-            "CC",
-            &[
-                "Cocos Islands",
-                "Cocos Keeling Islands",
-                "Cocos (Keeling) Islands",
-                "Territory of Cocos (Keeling) Islands",
-                "Cocos Islander",
-            ],
-        ),
-        (
-            "CD",
-            &[
-                "Congo-Kinshasa",
-                "Democratic Republic of the Congo",
-                "DR Congo",
-                "DRC",
-                "Congo, The Democratic Republic of the",
-                "Congolese",
-                "Congolese (Kinshasa)",
-            ],
-        ),
-        ("CF", &["Central African Republic", "Central African"]),
-        (
-            "CG",
-            &[
-                "Congo-Brazzaville",
-                "Republic of the Congo",
-                "Congolese (Brazzaville)",
-            ],
-        ),
-        ("CH", &["Switzerland", "Swiss"]),
-        ("CI", &["Côte d'Ivoire", "Ivory Coast", "Ivorian"]),
-        ("CK", &["Cook Islands", "Cook Islander"]),
-        ("CL", &["Chile", "Chilean"]),
-        ("CM", &["Cameroon", "Cameroonian"]),
-        ("CN", &["China", "Chinese"]),
-        ("CO", &["Colombia", "Colombian"]),
-        ("CP", &["Clipperton Island", "Clipperton Islander"]),
-        ("CQ", &["Sark", "Sercquiais"]),
-        ("CR", &["Costa Rica", "Costa Rican"]),
-        ("CU", &["Cuba", "Cuban"]),
-        (
-            "CV",
-            &[
-                "Cabo Verde",
-                "Cape Verde",
-                "Republic of Cabo Verde",
-                "Cape Verdean",
-            ],
-        ),
-        ("CW", &["Curaçao", "Curaçaoan"]),
-        ("CX", &["Christmas Island", "Christmas Islander"]),
-        ("CY", &["Cyprus", "Cypriot"]),
-        ("CZ", &["Czechia", "Czech Republic", "Czech"]),
-        ("DE", &["Germany", "German"]),
-        ("DG", &["Diego Garcia", "Diego Garcian"]),
-        ("DJ", &["Djibouti", "Djiboutian"]),
-        ("DK", &["Denmark", "Danish"]),
-        ("DM", &["Dominica", "Dominican"]),
-        ("DO", &["Dominican Republic", "Dominican"]),
-        ("DZ", &["Algeria", "Algerian"]),
-        ("EA", &["Ceuta & Melilla", "Ceutí, Melillense"]),
-        ("EC", &["Ecuador", "Ecuadorean"]),
-        ("EE", &["Estonia", "Estonian"]),
-        ("EG", &["Egypt", "Egyptian"]),
-        ("EH", &["Western Sahara", "Sahrawi"]),
-        ("ER", &["Eritrea", "Eritrean"]),
-        ("ES", &["Spain", "Spanish"]),
-        ("ET", &["Ethiopia", "Ethiopian"]),
-        ("EU", &["European Union", "European"]),
-        ("FI", &["Finland", "Finnish"]),
-        ("FJ", &["Fiji", "Fijian"]),
-        (
-            "FK",
-            &[
-                "Falkland Islands",
-                "Falkland Islands (Malvinas)",
-                "Falkland Islander",
-            ],
-        ),
-        (
-            "FM",
-            &[
-                "Micronesia",
-                "Federated States of Micronesia",
-                "Micronesian",
-            ],
-        ),
-        ("FO", &["Faroe Islands", "Faroese"]),
-        ("FR", &["France", "French"]),
-        ("GA", &["Gabon", "Gabonese"]),
-        ("GB", &["United Kingdom", "UK", "British"]),
-        ("GD", &["Grenada", "Grenadian"]),
-        ("GE", &["Georgia", "Georgian"]),
-        ("GF", &["French Guiana", "Guianan"]),
-        ("GG", &["Guernsey", "Guernsey person"]),
-        ("GH", &["Ghana", "Ghanaian"]),
-        ("GI", &["Gibraltar", "Gibraltarian"]),
-        ("GL", &["Greenland", "Greenlandic"]),
-        ("GM", &["Gambia", "Gambian"]),
-        ("GN", &["Guinea", "Guinean"]),
-        ("GP", &["Guadeloupe", "Guadeloupian"]),
-        ("GQ", &["Equatorial Guinea", "Equatorial Guinean"]),
-        ("GR", &["Greece", "Greek"]),
-        (
-            "GS",
-            &[
-                "South Georgia & South Sandwich Islands",
-                "South Georgian South Sandwich Islander",
-            ],
-        ),
-        ("GT", &["Guatemala", "Guatemalan"]),
-        ("GU", &["Guam", "Guamanian"]),
-        ("GW", &["Guinea-Bissau", "Guinea-Bissauan"]),
-        ("GY", &["Guyana", "Guyanese"]),
-        ("HK", &["Hong Kong", "Hong Kong SAR China", "Hong Konger"]),
-        (
-            "HM",
-            &["Heard & McDonald Islands", "Heard and McDonald Islander"],
-        ),
-        ("HN", &["Honduras", "Honduran"]),
-        ("HR", &["Croatia", "Croatian"]),
-        ("HT", &["Haiti", "Haitian"]),
-        ("HU", &["Hungary", "Hungarian"]),
-        ("IC", &["Canary Islands", "Canarian"]),
-        ("ID", &["Indonesia", "Indonesian"]),
-        ("IE", &["Ireland", "Irish"]),
-        ("IL", &["Israel", "Israeli"]),
-        ("IM", &["Isle of Man", "Manx"]),
-        ("IN", &["India", "Indian"]),
-        ("IO", &["British Indian Ocean Territory", "BIOT Islander"]),
-        ("IQ", &["Iraq", "Iraqi"]),
-        ("IR", &["Iran", "Islamic Republic of Iran", "Iranian"]),
-        ("IS", &["Iceland", "Icelander"]),
-        ("IT", &["Italy", "Italian"]),
-        ("JE", &["Jersey", "Jersey person"]),
-        ("JM", &["Jamaica", "Jamaican"]),
-        ("JO", &["Jordan", "Jordanian"]),
-        ("JP", &["Japan", "Japanese"]),
-        ("KE", &["Kenya", "Kenyan"]),
-        ("KG", &["Kyrgyzstan", "Kirghiz"]),
-        ("KH", &["Cambodia", "Cambodian"]),
-        ("KI", &["Kiribati", "I-Kiribati"]),
-        ("KM", &["Comoros", "Comoran"]),
-        (
-            "KN",
-            &[
-                "Saint Kitts & Nevis",
-                "St. Kitts & Nevis",
-                "Kittitian",
-                "Nevisian",
-                "Saint Kitts",
-                "St. Kitts",
-            ],
-        ),
-        (
-            "KP",
-            &[
-                "North Korea",
-                "DPRK",
-                "Korea, Democratic People's Republic of",
-                "Democratic People's Republic of Korea",
-                "North Korean",
-            ],
-        ),
-        ("KR", &["South Korea", "Republic of Korea", "South Korean"]),
-        ("KW", &["Kuwait", "Kuwaiti"]),
-        ("KY", &["Cayman Islands", "Caymanian"]),
-        ("KZ", &["Kazakhstan", "Kazakhstani"]),
-        (
-            "LA",
-            &["Laos", "Lao People's Democratic Republic", "Laotian"],
-        ),
-        ("LB", &["Lebanon", "Lebanese"]),
-        ("LC", &["Saint Lucia", "Saint Lucian"]),
-        ("LI", &["Liechtenstein", "Liechtensteiner"]),
-        ("LK", &["Sri Lanka", "Sri Lankan"]),
-        ("LR", &["Liberia", "Liberian"]),
-        ("LS", &["Lesotho", "Mosotho"]),
-        ("LT", &["Lithuania", "Lithuanian"]),
-        ("LU", &["Luxembourg", "Luxembourger"]),
-        ("LV", &["Latvia", "Latvian"]),
-        ("LY", &["Libya", "Libyan"]),
-        ("MA", &["Morocco", "Moroccan"]),
-        ("MC", &["Monaco", "Monegasque"]),
-        ("MD", &["Moldova", "Moldovan"]),
-        ("ME", &["Montenegro", "Montenegrin"]),
-        ("MF", &["Saint Martin", "Saint-Martinois"]),
-        ("MG", &["Madagascar", "Malagasy"]),
-        ("MH", &["Marshall Islands", "Marshallese"]),
-        (
-            "MK",
-            &[
-                "North Macedonia",
-                "Macedonia",
-                "Republic of North Macedonia",
-                "Macedonian",
-            ],
-        ),
-        ("ML", &["Mali", "Malian"]),
-        ("MM", &["Myanmar", "Burma", "Burmese"]),
-        ("MN", &["Mongolia", "Mongolian"]),
-        ("MO", &["Macao", "Macao SAR China", "Macau", "Macanese"]),
-        ("MP", &["Northern Mariana Islands", "Northern Marianan"]),
-        ("MQ", &["Martinique", "Martinican"]),
-        ("MR", &["Mauritania", "Mauritanian"]),
-        ("MS", &["Montserrat", "Montserratian"]),
-        ("MT", &["Malta", "Maltese"]),
-        ("MU", &["Mauritius", "Mauritian"]),
-        ("MV", &["Maldives", "Maldivan"]),
-        ("MW", &["Malawi", "Malawian"]),
-        ("MX", &["Mexico", "Mexican"]),
-        ("MY", &["Malaysia", "Malaysian"]),
-        ("MZ", &["Mozambique", "Mozambican"]),
-        ("NA", &["Namibia", "Namibian"]),
-        ("NC", &["New Caledonia", "New Caledonian"]),
-        ("NE", &["Niger", "Nigerien"]),
-        ("NF", &["Norfolk Island", "Norfolk Islander"]),
-        ("NG", &["Nigeria", "Nigerian"]),
-        ("NI", &["Nicaragua", "Nicaraguan"]),
-        ("NL", &["Netherlands", "Dutch"]),
-        ("NO", &["Norway", "Norwegian"]),
-        ("NP", &["Nepal", "Nepalese"]),
-        ("NR", &["Nauru", "Nauruan"]),
-        ("NU", &["Niue", "Niuean"]),
-        ("NZ", &["New Zealand", "New Zealander"]),
-        ("OM", &["Oman", "Omani"]),
-        ("PA", &["Panama", "Panamanian"]),
-        ("PE", &["Peru", "Peruvian"]),
-        ("PF", &["French Polynesia", "French Polynesian"]),
-        ("PG", &["Papua New Guinea", "Papua New Guinean"]),
-        ("PH", &["Philippines", "Filipino"]),
-        ("PK", &["Pakistan", "Pakistani"]),
-        ("PL", &["Poland", "Polish"]),
-        (
-            "PM",
-            &["Saint Pierre & Miquelon", "Saint-Pierrais, Miquelonnais"],
-        ),
-        ("PN", &["Pitcairn Islands", "Pitcairn", "Pitcairn Islander"]),
-        ("PR", &["Puerto Rico", "Puerto Rican"]),
-        (
-            "PS",
-            &[
-                "Palestine",
-                "State of Palestine",
-                "Palestinian Territories",
-                "Palestinian",
-            ],
-        ),
-        ("PT", &["Portugal", "Portuguese"]),
-        ("PW", &["Palau", "Palauan"]),
-        ("PY", &["Paraguay", "Paraguayan"]),
-        ("QA", &["Qatar", "Qatari"]),
-        ("RE", &["Réunion", "Reunion"]),
-        ("RO", &["Romania", "Romanian"]),
-        ("RS", &["Serbia", "Serbian"]),
-        ("RU", &["Russia", "Russian Federation", "Russian"]),
-        ("RW", &["Rwanda", "Rwandan"]),
-        ("SA", &["Saudi Arabia", "Saudi Arabian"]),
-        ("SB", &["Solomon Islands", "Solomon Islander"]),
-        ("SC", &["Seychelles", "Seychellois"]),
-        ("SD", &["Sudan", "Sudanese"]),
-        ("SE", &["Sweden", "Swedish"]),
-        ("SG", &["Singapore", "Singaporean"]),
-        ("SH", &["Saint Helena", "Saint Helenian"]),
-        ("SI", &["Slovenia", "Slovene"]),
-        ("SJ", &["Svalbard & Jan Mayen"]),
-        ("SK", &["Slovakia", "Slovak"]),
-        ("SL", &["Sierra Leone", "Sierra Leonean"]),
-        ("SM", &["San Marino", "Sammarinese"]),
-        ("SN", &["Senegal", "Senegalese"]),
-        ("SO", &["Somalia", "Somali"]),
-        ("SR", &["Suriname", "Surinamer"]),
-        ("SS", &["South Sudan", "South Sudanese"]),
-        (
-            "ST",
-            &["São Tomé & Príncipe", "Sao Tomean", "São Tomé", "Príncipe"],
-        ),
-        ("SV", &["El Salvador", "Salvadoran"]),
-        ("SX", &["Sint Maarten", "Sint Maarten (Dutch part)"]),
-        ("SY", &["Syria", "Syrian Arab Republic", "Syrian"]),
-        ("SZ", &["Eswatini", "Swaziland", "Swazi"]),
-        ("TA", &["Tristan da Cunha", "Tristanian"]),
-        ("TC", &["Turks & Caicos Islands", "Turks & Caicos Islander"]),
-        ("TD", &["Chad", "Chadian"]),
-        (
-            "TF",
-            &[
-                "French Southern Territories",
-                "French Southern Territory resident",
-            ],
-        ),
-        ("TG", &["Togo", "Togolese"]),
-        ("TH", &["Thailand", "Thai"]),
-        ("TJ", &["Tajikistan", "Tadzhik"]),
-        ("TK", &["Tokelau", "Tokelauan"]),
-        ("TL", &["Timor-Leste", "East Timor", "East Timorese"]),
-        ("TM", &["Turkmenistan", "Turkmen"]),
-        ("TN", &["Tunisia", "Tunisian"]),
-        ("TO", &["Tonga", "Tongan"]),
-        ("TR", &["Türkiye", "Turkey", "Turkish"]),
-        ("TT", &["Trinidad & Tobago", "Trinidadian"]),
-        ("TV", &["Tuvalu", "Tuvaluan"]),
-        ("TW", &["Taiwan", "Republic of China", "Taiwanese"]),
-        (
-            "TZ",
-            &["Tanzania", "United Republic of Tanzania", "Tanzanian"],
-        ),
-        ("UA", &["Ukraine", "Ukrainian"]),
-        ("UG", &["Uganda", "Ugandan"]),
-        (
-            "UM",
-            &[
-                "U.S. Outlying Islands",
-                "US Minor Outlying Islands",
-                "United States Minor Outlying Islands",
-            ],
-        ),
-        ("UN", &["United Nations", "UN member"]),
-        (
-            "US",
-            &[
-                "United States",
-                "USA",
-                "United States of America",
-                "American",
-            ],
-        ),
-        ("UY", &["Uruguay", "Uruguayan"]),
-        ("UZ", &["Uzbekistan", "Uzbekistani"]),
-        ("VA", &["Vatican City", "Holy See", "Vatican"]),
-        (
-            "VC",
-            &[
-                "Saint Vincent & the Grenadines",
-                "St. Vincent & the Grenadines",
-                "Vincentian",
-            ],
-        ),
-        (
-            "VE",
-            &[
-                "Venezuela",
-                "Bolivarian Republic of Venezuela",
-                "Venezuelan",
-            ],
-        ),
-        ("VG", &["British Virgin Islands"]),
-        ("VI", &["U.S. Virgin Islands", "US Virgin Islands"]),
-        ("VN", &["Vietnam", "Viet Nam", "Vietnamese"]),
-        ("VU", &["Vanuatu", "Ni-Vanuatu"]),
-        (
-            "WF",
-            &[
-                "Wallis & Futuna",
-                "Wallis and Futuna",
-                "Wallis and Futuna Islander",
-            ],
-        ),
-        ("WS", &["Samoa", "Samoan"]),
-        ("XK", &["Kosovo", "Kosovar"]),
-        ("YE", &["Yemen", "Yemeni"]),
-        ("YT", &["Mayotte", "Mahoran"]),
-        ("ZA", &["South Africa", "South African"]),
-        ("ZM", &["Zambia", "Zambian"]),
-        ("ZW", &["Zimbabwe", "Zimbabwean"]),
-    ]
-});
+// The dataset, written once as one `CODE(alpha3, numeric, names, demonyms)` entry per country
+// and handed to `$callback`. `COUNTRIES` and `CountryCode` are both built from it, so they
+// always list the same codes.
+macro_rules! country_data {
+    ($callback:ident) => {
+        $callback! {
+            AC("ASC", "", &["Ascension Island"], &["Ascension Islander"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            AD("AND", "020", &["Andorra"], &["Andorran"]),
+            AE("ARE", "784", &["United Arab Emirates", "UAE"], &["Emirati"]),
+            AF("AFG", "004", &["Afghanistan"], &["Afghan"]),
+            AG("ATG", "028", &["Antigua & Barbuda"], &["Antiguan", "Barbudan"]),
+            AI("AIA", "660", &["Anguilla"], &["Anguillian"])
+                .territory(),
+            AL("ALB", "008", &["Albania"], &["Albanian"]),
+            AM("ARM", "051", &["Armenia"], &["Armenian"]),
+            // Dissolved 2010, kept for legacy compatibility
+            AN("ANT", "530", &["Netherlands Antilles"], &["Netherlands Antillean"])
+                .with_status(CodeStatus::TransitionallyReserved)
+                .territory(),
+            AO("AGO", "024", &["Angola"], &["Angolan"]),
+            AQ("ATA", "010", &["Antarctica"], &["Antarctican"])
+                .territory(),
+            AR("ARG", "032", &["Argentina"], &["Argentine"]),
+            AS("ASM", "016", &["American Samoa"], &["American Samoan"])
+                .territory(),
+            AT("AUT", "040", &["Austria"], &["Austrian"]),
+            AU("AUS", "036", &["Australia"], &["Australian"]),
+            AW("ABW", "533", &["Aruba"], &["Aruban"])
+                .territory(),
+            AX("ALA", "248", &["Åland Islands"], &["Ålandish"])
+                .territory(),
+            AZ("AZE", "031", &["Azerbaijan"], &["Azerbaijani"]),
+            BA("BIH", "070", &["Bosnia & Herzegovina"], &["Bosnian", "Herzegovinian"]),
+            BB("BRB", "052", &["Barbados"], &["Barbadian"]),
+            BD("BGD", "050", &["Bangladesh"], &["Bangladeshi"]),
+            BE("BEL", "056", &["Belgium"], &["Belgian"]),
+            BF("BFA", "854", &["Burkina Faso"], &["Burkinabe"]),
+            BG("BGR", "100", &["Bulgaria"], &["Bulgarian"]),
+            BH("BHR", "048", &["Bahrain"], &["Bahraini"]),
+            BI("BDI", "108", &["Burundi"], &["Burundian"]),
+            BJ("BEN", "204", &["Benin"], &["Beninese"]),
+            BL("BLM", "652", &["Saint Barthélemy"], &["Barthélemois"])
+                .territory(),
+            BM("BMU", "060", &["Bermuda"], &["Bermudian"])
+                .territory(),
+            BN("BRN", "096", &["Brunei", "Brunei Darussalam"], &["Bruneian"]),
+            BO("BOL", "068", &["Bolivia", "Plurinational State of Bolivia"], &["Bolivian"]),
+            BQ(
+                "BES",
+                "535",
+                &[
+                    "Caribbean Netherlands",
+                    "Bonaire, Sint Eustatius and Saba",
+                    "BES Islands",
+                ],
+                &["Caribbean Dutch"],
+            )
+                .territory(),
+            BR("BRA", "076", &["Brazil"], &["Brazilian"]),
+            BS("BHS", "044", &["Bahamas"], &["Bahamian"]),
+            BT("BTN", "064", &["Bhutan"], &["Bhutanese"]),
+            BV("BVT", "074", &["Bouvet Island"], &["Bouvet Islander"])
+                .territory(),
+            BW("BWA", "072", &["Botswana"], &["Motswana"]),
+            BY("BLR", "112", &["Belarus"], &["Belarusian"]),
+            BZ("BLZ", "084", &["Belize"], &["Belizean"]),
+            CA("CAN", "124", &["Canada"], &["Canadian"]),
+            CC(
+                "CCK",
+                "166",
+                &[
+                    "Cocos Islands",
+                    "Cocos Keeling Islands",
+                    "Cocos (Keeling) Islands",
+                    "Territory of Cocos (Keeling) Islands",
+                ],
+                &["Cocos Islander"],
+            )
+                .territory(),
+            CD(
+                "COD",
+                "180",
+                &[
+                    "Congo-Kinshasa",
+                    "Democratic Republic of the Congo",
+                    "DR Congo",
+                    "DRC",
+                    "Congo, The Democratic Republic of the",
+                ],
+                &["Congolese", "Congolese (Kinshasa)"],
+            ),
+            CF("CAF", "140", &["Central African Republic"], &["Central African"]),
+            CG(
+                "COG",
+                "178",
+                &["Congo-Brazzaville", "Republic of the Congo"],
+                &["Congolese (Brazzaville)"],
+            ),
+            CH("CHE", "756", &["Switzerland"], &["Swiss"]),
+            CI("CIV", "384", &["Côte d'Ivoire", "Ivory Coast"], &["Ivorian"]),
+            CK("COK", "184", &["Cook Islands"], &["Cook Islander"])
+                .territory(),
+            CL("CHL", "152", &["Chile"], &["Chilean"]),
+            CM("CMR", "120", &["Cameroon"], &["Cameroonian"]),
+            CN("CHN", "156", &["China"], &["Chinese"]),
+            CO("COL", "170", &["Colombia"], &["Colombian"]),
+            CP("CPT", "", &["Clipperton Island"], &["Clipperton Islander"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            CQ("", "", &["Sark"], &["Sercquiais"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            CR("CRI", "188", &["Costa Rica"], &["Costa Rican"]),
+            CU("CUB", "192", &["Cuba"], &["Cuban"]),
+            CV(
+                "CPV",
+                "132",
+                &["Cabo Verde", "Cape Verde", "Republic of Cabo Verde"],
+                &["Cape Verdean"],
+            ),
+            CW("CUW", "531", &["Curaçao"], &["Curaçaoan"])
+                .territory(),
+            CX("CXR", "162", &["Christmas Island"], &["Christmas Islander"])
+                .territory(),
+            CY("CYP", "196", &["Cyprus"], &["Cypriot"]),
+            CZ("CZE", "203", &["Czechia", "Czech Republic"], &["Czech"]),
+            DE("DEU", "276", &["Germany"], &["German"]),
+            DG("DGA", "", &["Diego Garcia"], &["Diego Garcian"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            DJ("DJI", "262", &["Djibouti"], &["Djiboutian"]),
+            DK("DNK", "208", &["Denmark"], &["Danish"]),
+            DM("DMA", "212", &["Dominica"], &["Dominican"]),
+            DO("DOM", "214", &["Dominican Republic"], &["Dominican"]),
+            DZ("DZA", "012", &["Algeria"], &["Algerian"]),
+            EA("", "", &["Ceuta & Melilla"], &["Ceutí", "Melillense"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            EC("ECU", "218", &["Ecuador"], &["Ecuadorean"]),
+            EE("EST", "233", &["Estonia"], &["Estonian"]),
+            EG("EGY", "818", &["Egypt"], &["Egyptian"]),
+            EH("ESH", "732", &["Western Sahara"], &["Sahrawi"])
+                .territory(),
+            ER("ERI", "232", &["Eritrea"], &["Eritrean"]),
+            ES("ESP", "724", &["Spain"], &["Spanish"]),
+            ET("ETH", "231", &["Ethiopia"], &["Ethiopian"]),
+            EU("", "", &["European Union"], &["European"])
+                .with_status(CodeStatus::ExceptionallyReserved),
+            FI("FIN", "246", &["Finland"], &["Finnish"]),
+            FJ("FJI", "242", &["Fiji"], &["Fijian"]),
+            FK(
+                "FLK",
+                "238",
+                &["Falkland Islands", "Falkland Islands (Malvinas)"],
+                &["Falkland Islander"],
+            )
+                .territory(),
+            FM("FSM", "583", &["Micronesia", "Federated States of Micronesia"], &["Micronesian"]),
+            FO("FRO", "234", &["Faroe Islands"], &["Faroese"])
+                .territory(),
+            FR("FRA", "250", &["France"], &["French"]),
+            GA("GAB", "266", &["Gabon"], &["Gabonese"]),
+            GB("GBR", "826", &["United Kingdom", "UK"], &["British"]),
+            GD("GRD", "308", &["Grenada"], &["Grenadian"]),
+            GE("GEO", "268", &["Georgia"], &["Georgian"]),
+            GF("GUF", "254", &["French Guiana"], &["Guianan"])
+                .territory(),
+            GG("GGY", "831", &["Guernsey"], &["Guernsey person"])
+                .territory(),
+            GH("GHA", "288", &["Ghana"], &["Ghanaian"]),
+            GI("GIB", "292", &["Gibraltar"], &["Gibraltarian"])
+                .territory(),
+            GL("GRL", "304", &["Greenland"], &["Greenlandic"])
+                .territory(),
+            GM("GMB", "270", &["Gambia"], &["Gambian"]),
+            GN("GIN", "324", &["Guinea"], &["Guinean"]),
+            GP("GLP", "312", &["Guadeloupe"], &["Guadeloupian"])
+                .territory(),
+            GQ("GNQ", "226", &["Equatorial Guinea"], &["Equatorial Guinean"]),
+            GR("GRC", "300", &["Greece"], &["Greek"]),
+            GS(
+                "SGS",
+                "239",
+                &["South Georgia & South Sandwich Islands"],
+                &["South Georgian South Sandwich Islander"],
+            )
+                .territory(),
+            GT("GTM", "320", &["Guatemala"], &["Guatemalan"]),
+            GU("GUM", "316", &["Guam"], &["Guamanian"])
+                .territory(),
+            GW("GNB", "624", &["Guinea-Bissau"], &["Guinea-Bissauan"]),
+            GY("GUY", "328", &["Guyana"], &["Guyanese"]),
+            HK("HKG", "344", &["Hong Kong", "Hong Kong SAR China"], &["Hong Konger"])
+                .territory(),
+            HM("HMD", "334", &["Heard & McDonald Islands"], &["Heard and McDonald Islander"])
+                .territory(),
+            HN("HND", "340", &["Honduras"], &["Honduran"]),
+            HR("HRV", "191", &["Croatia"], &["Croatian"]),
+            HT("HTI", "332", &["Haiti"], &["Haitian"]),
+            HU("HUN", "348", &["Hungary"], &["Hungarian"]),
+            IC("", "", &["Canary Islands"], &["Canarian"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            ID("IDN", "360", &["Indonesia"], &["Indonesian"]),
+            IE("IRL", "372", &["Ireland"], &["Irish"]),
+            IL("ISR", "376", &["Israel"], &["Israeli"]),
+            IM("IMN", "833", &["Isle of Man"], &["Manx"])
+                .territory(),
+            IN("IND", "356", &["India"], &["Indian"]),
+            IO("IOT", "086", &["British Indian Ocean Territory"], &["BIOT Islander"])
+                .territory(),
+            IQ("IRQ", "368", &["Iraq"], &["Iraqi"]),
+            IR("IRN", "364", &["Iran", "Islamic Republic of Iran"], &["Iranian"]),
+            IS("ISL", "352", &["Iceland"], &["Icelander"]),
+            IT("ITA", "380", &["Italy"], &["Italian"]),
+            JE("JEY", "832", &["Jersey"], &["Jersey person"])
+                .territory(),
+            JM("JAM", "388", &["Jamaica"], &["Jamaican"]),
+            JO("JOR", "400", &["Jordan"], &["Jordanian"]),
+            JP("JPN", "392", &["Japan"], &["Japanese"]),
+            KE("KEN", "404", &["Kenya"], &["Kenyan"]),
+            KG("KGZ", "417", &["Kyrgyzstan"], &["Kirghiz"]),
+            KH("KHM", "116", &["Cambodia"], &["Cambodian"]),
+            KI("KIR", "296", &["Kiribati"], &["I-Kiribati"]),
+            KM("COM", "174", &["Comoros"], &["Comoran"]),
+            KN(
+                "KNA",
+                "659",
+                &[
+                    "Saint Kitts & Nevis",
+                    "St. Kitts & Nevis",
+                    "Saint Kitts",
+                    "St. Kitts",
+                ],
+                &["Kittitian", "Nevisian"],
+            ),
+            KP(
+                "PRK",
+                "408",
+                &[
+                    "North Korea",
+                    "DPRK",
+                    "Korea, Democratic People's Republic of",
+                    "Democratic People's Republic of Korea",
+                ],
+                &["North Korean"],
+            ),
+            KR("KOR", "410", &["South Korea", "Republic of Korea"], &["South Korean"]),
+            KW("KWT", "414", &["Kuwait"], &["Kuwaiti"]),
+            KY("CYM", "136", &["Cayman Islands"], &["Caymanian"])
+                .territory(),
+            KZ("KAZ", "398", &["Kazakhstan"], &["Kazakhstani"]),
+            LA("LAO", "418", &["Laos", "Lao People's Democratic Republic"], &["Laotian"]),
+            LB("LBN", "422", &["Lebanon"], &["Lebanese"]),
+            LC("LCA", "662", &["Saint Lucia"], &["Saint Lucian"]),
+            LI("LIE", "438", &["Liechtenstein"], &["Liechtensteiner"]),
+            LK("LKA", "144", &["Sri Lanka"], &["Sri Lankan"]),
+            LR("LBR", "430", &["Liberia"], &["Liberian"]),
+            LS("LSO", "426", &["Lesotho"], &["Mosotho"]),
+            LT("LTU", "440", &["Lithuania"], &["Lithuanian"]),
+            LU("LUX", "442", &["Luxembourg"], &["Luxembourger"]),
+            LV("LVA", "428", &["Latvia"], &["Latvian"]),
+            LY("LBY", "434", &["Libya"], &["Libyan"]),
+            MA("MAR", "504", &["Morocco"], &["Moroccan"]),
+            MC("MCO", "492", &["Monaco"], &["Monegasque"]),
+            MD("MDA", "498", &["Moldova"], &["Moldovan"]),
+            ME("MNE", "499", &["Montenegro"], &["Montenegrin"]),
+            MF("MAF", "663", &["Saint Martin"], &["Saint-Martinois"])
+                .territory(),
+            MG("MDG", "450", &["Madagascar"], &["Malagasy"]),
+            MH("MHL", "584", &["Marshall Islands"], &["Marshallese"]),
+            MK(
+                "MKD",
+                "807",
+                &[
+                    "North Macedonia",
+                    "Macedonia",
+                    "Republic of North Macedonia",
+                ],
+                &["Macedonian"],
+            ),
+            ML("MLI", "466", &["Mali"], &["Malian"]),
+            MM("MMR", "104", &["Myanmar", "Burma"], &["Burmese"]),
+            MN("MNG", "496", &["Mongolia"], &["Mongolian"]),
+            MO("MAC", "446", &["Macao", "Macao SAR China", "Macau"], &["Macanese"])
+                .territory(),
+            MP("MNP", "580", &["Northern Mariana Islands"], &["Northern Marianan"])
+                .territory(),
+            MQ("MTQ", "474", &["Martinique"], &["Martinican"])
+                .territory(),
+            MR("MRT", "478", &["Mauritania"], &["Mauritanian"]),
+            MS("MSR", "500", &["Montserrat"], &["Montserratian"])
+                .territory(),
+            MT("MLT", "470", &["Malta"], &["Maltese"]),
+            MU("MUS", "480", &["Mauritius"], &["Mauritian"]),
+            MV("MDV", "462", &["Maldives"], &["Maldivan"]),
+            MW("MWI", "454", &["Malawi"], &["Malawian"]),
+            MX("MEX", "484", &["Mexico"], &["Mexican"]),
+            MY("MYS", "458", &["Malaysia"], &["Malaysian"]),
+            MZ("MOZ", "508", &["Mozambique"], &["Mozambican"]),
+            NA("NAM", "516", &["Namibia"], &["Namibian"]),
+            NC("NCL", "540", &["New Caledonia"], &["New Caledonian"])
+                .territory(),
+            NE("NER", "562", &["Niger"], &["Nigerien"]),
+            NF("NFK", "574", &["Norfolk Island"], &["Norfolk Islander"])
+                .territory(),
+            NG("NGA", "566", &["Nigeria"], &["Nigerian"]),
+            NI("NIC", "558", &["Nicaragua"], &["Nicaraguan"]),
+            NL("NLD", "528", &["Netherlands"], &["Dutch"]),
+            NO("NOR", "578", &["Norway"], &["Norwegian"]),
+            NP("NPL", "524", &["Nepal"], &["Nepalese"]),
+            NR("NRU", "520", &["Nauru"], &["Nauruan"]),
+            NU("NIU", "570", &["Niue"], &["Niuean"])
+                .territory(),
+            NZ("NZL", "554", &["New Zealand"], &["New Zealander"]),
+            OM("OMN", "512", &["Oman"], &["Omani"]),
+            PA("PAN", "591", &["Panama"], &["Panamanian"]),
+            PE("PER", "604", &["Peru"], &["Peruvian"]),
+            PF("PYF", "258", &["French Polynesia"], &["French Polynesian"])
+                .territory(),
+            PG("PNG", "598", &["Papua New Guinea"], &["Papua New Guinean"]),
+            PH("PHL", "608", &["Philippines"], &["Filipino"]),
+            PK("PAK", "586", &["Pakistan"], &["Pakistani"]),
+            PL("POL", "616", &["Poland"], &["Polish"]),
+            PM("SPM", "666", &["Saint Pierre & Miquelon"], &["Saint-Pierrais", "Miquelonnais"])
+                .territory(),
+            PN("PCN", "612", &["Pitcairn Islands", "Pitcairn"], &["Pitcairn Islander"])
+                .territory(),
+            PR("PRI", "630", &["Puerto Rico"], &["Puerto Rican"])
+                .territory(),
+            PS(
+                "PSE",
+                "275",
+                &["Palestine", "State of Palestine", "Palestinian Territories"],
+                &["Palestinian"],
+            ),
+            PT("PRT", "620", &["Portugal"], &["Portuguese"]),
+            PW("PLW", "585", &["Palau"], &["Palauan"]),
+            PY("PRY", "600", &["Paraguay"], &["Paraguayan"]),
+            QA("QAT", "634", &["Qatar"], &["Qatari"]),
+            RE("REU", "638", &["Réunion", "Reunion"], &["Réunionese"])
+                .territory(),
+            RO("ROU", "642", &["Romania"], &["Romanian"]),
+            RS("SRB", "688", &["Serbia"], &["Serbian"]),
+            RU("RUS", "643", &["Russia", "Russian Federation"], &["Russian"]),
+            RW("RWA", "646", &["Rwanda"], &["Rwandan"]),
+            SA("SAU", "682", &["Saudi Arabia"], &["Saudi Arabian"]),
+            SB("SLB", "090", &["Solomon Islands"], &["Solomon Islander"]),
+            SC("SYC", "690", &["Seychelles"], &["Seychellois"]),
+            SD("SDN", "729", &["Sudan"], &["Sudanese"]),
+            SE("SWE", "752", &["Sweden"], &["Swedish"]),
+            SG("SGP", "702", &["Singapore"], &["Singaporean"]),
+            SH("SHN", "654", &["Saint Helena"], &["Saint Helenian"])
+                .territory(),
+            SI("SVN", "705", &["Slovenia"], &["Slovene"]),
+            SJ("SJM", "744", &["Svalbard & Jan Mayen"], &[])
+                .territory(),
+            SK("SVK", "703", &["Slovakia"], &["Slovak"]),
+            SL("SLE", "694", &["Sierra Leone"], &["Sierra Leonean"]),
+            SM("SMR", "674", &["San Marino"], &["Sammarinese"]),
+            SN("SEN", "686", &["Senegal"], &["Senegalese"]),
+            SO("SOM", "706", &["Somalia"], &["Somali"]),
+            SR("SUR", "740", &["Suriname"], &["Surinamer"]),
+            SS("SSD", "728", &["South Sudan"], &["South Sudanese"]),
+            ST("STP", "678", &["São Tomé & Príncipe", "São Tomé", "Príncipe"], &["Sao Tomean"]),
+            SV("SLV", "222", &["El Salvador"], &["Salvadoran"]),
+            SX("SXM", "534", &["Sint Maarten", "Sint Maarten (Dutch part)"], &["Sint Maartener"])
+                .territory(),
+            SY("SYR", "760", &["Syria", "Syrian Arab Republic"], &["Syrian"]),
+            SZ("SWZ", "748", &["Eswatini", "Swaziland"], &["Swazi"]),
+            TA("TAA", "", &["Tristan da Cunha"], &["Tristanian"])
+                .with_status(CodeStatus::ExceptionallyReserved)
+                .territory(),
+            TC("TCA", "796", &["Turks & Caicos Islands"], &["Turks & Caicos Islander"])
+                .territory(),
+            TD("TCD", "148", &["Chad"], &["Chadian"]),
+            TF(
+                "ATF",
+                "260",
+                &["French Southern Territories"],
+                &["French Southern Territory resident"],
+            )
+                .territory(),
+            TG("TGO", "768", &["Togo"], &["Togolese"]),
+            TH("THA", "764", &["Thailand"], &["Thai"]),
+            TJ("TJK", "762", &["Tajikistan"], &["Tadzhik"]),
+            TK("TKL", "772", &["Tokelau"], &["Tokelauan"])
+                .territory(),
+            TL("TLS", "626", &["Timor-Leste", "East Timor"], &["East Timorese"]),
+            TM("TKM", "795", &["Turkmenistan"], &["Turkmen"]),
+            TN("TUN", "788", &["Tunisia"], &["Tunisian"]),
+            TO("TON", "776", &["Tonga"], &["Tongan"]),
+            TR("TUR", "792", &["Türkiye", "Turkey"], &["Turkish"]),
+            TT("TTO", "780", &["Trinidad & Tobago"], &["Trinidadian"]),
+            TV("TUV", "798", &["Tuvalu"], &["Tuvaluan"]),
+            TW("TWN", "158", &["Taiwan", "Republic of China"], &["Taiwanese"]),
+            TZ("TZA", "834", &["Tanzania", "United Republic of Tanzania"], &["Tanzanian"]),
+            UA("UKR", "804", &["Ukraine"], &["Ukrainian"]),
+            UG("UGA", "800", &["Uganda"], &["Ugandan"]),
+            UM(
+                "UMI",
+                "581",
+                &[
+                    "U.S. Outlying Islands",
+                    "US Minor Outlying Islands",
+                    "United States Minor Outlying Islands",
+                ],
+                &[],
+            )
+                .territory(),
+            UN("", "", &["United Nations"], &["UN member"])
+                .with_status(CodeStatus::ExceptionallyReserved),
+            US("USA", "840", &["United States", "USA", "United States of America"], &["American"]),
+            UY("URY", "858", &["Uruguay"], &["Uruguayan"]),
+            UZ("UZB", "860", &["Uzbekistan"], &["Uzbekistani"]),
+            VA("VAT", "336", &["Vatican City", "Holy See", "Vatican"], &[]),
+            VC(
+                "VCT",
+                "670",
+                &[
+                    "Saint Vincent & the Grenadines",
+                    "St. Vincent & the Grenadines",
+                ],
+                &["Vincentian"],
+            ),
+            VE("VEN", "862", &["Venezuela", "Bolivarian Republic of Venezuela"], &["Venezuelan"]),
+            VG("VGB", "092", &["British Virgin Islands"], &["British Virgin Islander"])
+                .territory(),
+            VI(
+                "VIR",
+                "850",
+                &["U.S. Virgin Islands", "US Virgin Islands"],
+                &["U.S. Virgin Islander"],
+            )
+                .territory(),
+            VN("VNM", "704", &["Vietnam", "Viet Nam"], &["Vietnamese"]),
+            VU("VUT", "548", &["Vanuatu"], &["Ni-Vanuatu"]),
+            WF(
+                "WLF",
+                "876",
+                &["Wallis & Futuna", "Wallis and Futuna"],
+                &["Wallis and Futuna Islander"],
+            )
+                .territory(),
+            WS("WSM", "882", &["Samoa"], &["Samoan"]),
+            XK("XKX", "", &["Kosovo"], &["Kosovar"])
+                .with_status(CodeStatus::UserAssigned),
+            YE("YEM", "887", &["Yemen"], &["Yemeni"]),
+            YT("MYT", "175", &["Mayotte"], &["Mahoran"])
+                .territory(),
+            ZA("ZAF", "710", &["South Africa"], &["South African"]),
+            ZM("ZMB", "894", &["Zambia"], &["Zambian"]),
+            ZW("ZWE", "716", &["Zimbabwe"], &["Zimbabwean"]),
+        }
+    };
+}
+
+pub(crate) use country_data;
+
+macro_rules! countries {
+    ($($code:ident($($arg:expr),+ $(,)?) $(.$method:ident($($method_arg:expr),*))*),+ $(,)?) => {
+        pub(crate) static COUNTRIES: Lazy<Vec<Country>> = Lazy::new(|| {
+            vec![$(Country::new(stringify!($code), $($arg),+)$(.$method($($method_arg),*))*),+]
+        });
+    };
+}
+
+country_data!(countries);
 
 pub(crate) static COUNTRIES_BY_CODE_INDEX: Lazy<
     [Option<&'static Country>; COUNTRY_CODE_INDEX_SIZE],
@@ -451,10 +531,44 @@ pub(crate) static COUNTRIES_BY_CODE_INDEX: Lazy<
         [None; COUNTRY_CODE_INDEX_SIZE];
 
     for country in COUNTRIES.iter() {
-        if let Some(index) = country_code_index_from_bytes(country.0.as_bytes()) {
+        if let Some(index) = country_code_index_from_bytes(country.code().as_bytes()) {
             countries_by_code[index] = Some(country);
         }
     }
 
     countries_by_code
 });
+
+pub(crate) static COUNTRIES_BY_ALPHA3_INDEX: Lazy<Vec<Option<&'static Country>>> =
+    Lazy::new(|| {
+        let mut countries_by_alpha3 = vec![None; ALPHA3_CODE_INDEX_SIZE];
+
+        for country in COUNTRIES.iter() {
+            if let Some(index) = country
+                .alpha3()
+                .and_then(|alpha3| alpha3_code_index_from_bytes(alpha3.as_bytes()))
+            {
+                countries_by_alpha3[index] = Some(country);
+            }
+        }
+
+        countries_by_alpha3
+    });
+
+pub(crate) static COUNTRIES_BY_NUMERIC_INDEX: Lazy<
+    [Option<&'static Country>; NUMERIC_CODE_INDEX_SIZE],
+> = Lazy::new(|| {
+    let mut countries_by_numeric: [Option<&'static Country>; NUMERIC_CODE_INDEX_SIZE] =
+        [None; NUMERIC_CODE_INDEX_SIZE];
+
+    for country in COUNTRIES.iter() {
+        if let Some(index) = country
+            .numeric()
+            .and_then(|numeric| numeric_code_index_from_bytes(numeric.as_bytes()))
+        {
+            countries_by_numeric[index] = Some(country);
+        }
+    }
+
+    countries_by_numeric
+});
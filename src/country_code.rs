@@ -0,0 +1,130 @@
+use crate::countries::{country_code_index_from_bytes, COUNTRY_CODE_INDEX_SIZE};
+use crate::{get_by_code, Country};
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! country_codes {
+    ($($variant:ident($($arg:expr),+ $(,)?) $(.$method:ident($($method_arg:expr),*))*),+ $(,)?) => {
+        /// An ISO 3166-1 alpha-2 code known to the built-in dataset.
+        ///
+        /// Using `CountryCode` instead of a string literal lets the compiler reject typos in
+        /// hardcoded codes. Every variant resolves to a [`Country`].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use country_emoji::{code_to_flag, code_to_name, CountryCode};
+        ///
+        /// assert_eq!(code_to_name(CountryCode::DE), Some("Germany"));
+        /// assert_eq!(code_to_flag(CountryCode::JP), Some("🇯🇵".to_string()));
+        ///
+        /// let code: CountryCode = "br".parse().unwrap();
+        /// assert_eq!(code, CountryCode::BR);
+        /// assert_eq!(code.to_string(), "BR");
+        /// assert_eq!(code.country().name(), "Brazil");
+        /// ```
+        #[non_exhaustive]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum CountryCode {
+            $($variant),+
+        }
+
+        impl CountryCode {
+            /// Every code in the built-in dataset, in alphabetical order.
+            pub const ALL: &'static [CountryCode] = &[$(CountryCode::$variant),+];
+
+            /// Returns the code as an uppercase string slice.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(CountryCode::$variant => stringify!($variant)),+
+                }
+            }
+        }
+    };
+}
+
+// Built from the entries of `COUNTRIES`, keeping only their codes.
+crate::countries::country_data!(country_codes);
+
+static COUNTRY_CODES_BY_INDEX: Lazy<[Option<CountryCode>; COUNTRY_CODE_INDEX_SIZE]> =
+    Lazy::new(|| {
+        let mut codes_by_index = [None; COUNTRY_CODE_INDEX_SIZE];
+
+        for &code in CountryCode::ALL {
+            if let Some(index) = country_code_index_from_bytes(code.as_str().as_bytes()) {
+                codes_by_index[index] = Some(code);
+            }
+        }
+
+        codes_by_index
+    });
+
+impl CountryCode {
+    /// Returns the dataset entry for this code.
+    pub fn country(self) -> &'static Country {
+        get_by_code(self.as_str()).expect("every CountryCode has a dataset entry")
+    }
+
+    /// Returns the preferred display name.
+    pub fn name(self) -> &'static str {
+        self.country().name()
+    }
+
+    /// Returns the Unicode flag emoji.
+    pub fn flag(self) -> String {
+        self.country().flag()
+    }
+}
+
+/// The error returned when a string is not a known ISO 3166-1 alpha-2 code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCountryCodeError {
+    input: String,
+}
+
+impl ParseCountryCodeError {
+    pub(crate) fn new(input: impl Into<String>) -> Self {
+        Self {
+            input: input.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseCountryCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown country code \"{}\"", self.input)
+    }
+}
+
+impl std::error::Error for ParseCountryCodeError {}
+
+impl FromStr for CountryCode {
+    type Err = ParseCountryCodeError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        country_code_index_from_bytes(code.trim().as_bytes())
+            .and_then(|index| COUNTRY_CODES_BY_INDEX[index])
+            .ok_or_else(|| ParseCountryCodeError::new(code))
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = ParseCountryCodeError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        code.parse()
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for CountryCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
@@ -0,0 +1,324 @@
+use crate::countries::{country_code_index_from_bytes, COUNTRY_CODE_INDEX_SIZE};
+use crate::country_code_index;
+use once_cell::sync::Lazy;
+
+static ENDONYMS_BY_CODE_INDEX: Lazy<[Option<&'static [&'static str]>; COUNTRY_CODE_INDEX_SIZE]> =
+    Lazy::new(|| {
+        let mut endonyms_by_code = [None; COUNTRY_CODE_INDEX_SIZE];
+        for &(code, names) in ENDONYMS {
+            if let Some(index) = country_code_index_from_bytes(code.as_bytes()) {
+                endonyms_by_code[index] = Some(names);
+            }
+        }
+        endonyms_by_code
+    });
+
+pub(crate) fn endonyms(code: &str) -> Option<&'static [&'static str]> {
+    country_code_index(code).and_then(|index| ENDONYMS_BY_CODE_INDEX[index])
+}
+
+// Names in each entry's own official languages, preferred name first, followed by common
+// Latin transliterations of non-Latin names.
+pub(crate) const ENDONYMS: &[(&str, &[&str])] = &[
+    ("AC", &["Ascension Island"]),
+    ("AD", &["Andorra"]),
+    (
+        "AE",
+        &[
+            "الإمارات العربية المتحدة",
+            "Al-Imārāt al-ʿArabiyya al-Muttaḥida",
+        ],
+    ),
+    ("AF", &["افغانستان", "Afghānistān"]),
+    ("AG", &["Antigua and Barbuda"]),
+    ("AI", &["Anguilla"]),
+    ("AL", &["Shqipëria"]),
+    ("AM", &["Հայաստան", "Hayastan"]),
+    ("AN", &["Nederlandse Antillen"]),
+    ("AO", &["Angola"]),
+    ("AQ", &["Antarctica"]),
+    ("AR", &["Argentina"]),
+    ("AS", &["American Samoa", "Amerika Sāmoa"]),
+    ("AT", &["Österreich"]),
+    ("AU", &["Australia"]),
+    ("AW", &["Aruba"]),
+    ("AX", &["Åland"]),
+    ("AZ", &["Azərbaycan"]),
+    ("BA", &["Bosna i Hercegovina", "Босна и Херцеговина"]),
+    ("BB", &["Barbados"]),
+    ("BD", &["বাংলাদেশ", "Bangladesh"]),
+    ("BE", &["België", "Belgique", "Belgien"]),
+    ("BF", &["Burkina Faso"]),
+    ("BG", &["България", "Balgariya"]),
+    ("BH", &["البحرين", "Al-Baḥrayn"]),
+    ("BI", &["Uburundi", "Burundi"]),
+    ("BJ", &["Bénin"]),
+    ("BL", &["Saint-Barthélemy"]),
+    ("BM", &["Bermuda"]),
+    ("BN", &["Brunei Darussalam"]),
+    ("BO", &["Bolivia", "Buliwya", "Wuliwya"]),
+    ("BQ", &["Caribisch Nederland"]),
+    ("BR", &["Brasil"]),
+    ("BS", &["The Bahamas"]),
+    ("BT", &["འབྲུག་ཡུལ་", "Druk Yul"]),
+    ("BV", &["Bouvetøya"]),
+    ("BW", &["Botswana"]),
+    ("BY", &["Беларусь", "Biełaruś"]),
+    ("BZ", &["Belize"]),
+    ("CA", &["Canada"]),
+    ("CC", &["Cocos (Keeling) Islands"]),
+    ("CD", &["République démocratique du Congo"]),
+    (
+        "CF",
+        &["Ködörösêse tî Bêafrîka", "République centrafricaine"],
+    ),
+    ("CG", &["République du Congo"]),
+    ("CH", &["Schweiz", "Suisse", "Svizzera", "Svizra"]),
+    ("CI", &["Côte d'Ivoire"]),
+    ("CK", &["Cook Islands", "Kūki ʻĀirani"]),
+    ("CL", &["Chile"]),
+    ("CM", &["Cameroun", "Cameroon"]),
+    ("CN", &["中国", "Zhōngguó"]),
+    ("CO", &["Colombia"]),
+    ("CP", &["Île de Clipperton"]),
+    ("CQ", &["Sark", "Sercq"]),
+    ("CR", &["Costa Rica"]),
+    ("CU", &["Cuba"]),
+    ("CV", &["Cabo Verde"]),
+    ("CW", &["Curaçao", "Kòrsou"]),
+    ("CX", &["Christmas Island"]),
+    ("CY", &["Κύπρος", "Kıbrıs", "Kýpros"]),
+    ("CZ", &["Česko", "Česká republika"]),
+    ("DE", &["Deutschland"]),
+    ("DG", &["Diego Garcia"]),
+    ("DJ", &["Djibouti", "جيبوتي"]),
+    ("DK", &["Danmark"]),
+    ("DM", &["Dominica"]),
+    ("DO", &["República Dominicana"]),
+    ("DZ", &["الجزائر", "Al-Jazāʾir"]),
+    ("EA", &["Ceuta y Melilla"]),
+    ("EC", &["Ecuador"]),
+    ("EE", &["Eesti"]),
+    ("EG", &["مصر", "Miṣr"]),
+    ("EH", &["الصحراء الغربية", "Sáhara Occidental"]),
+    ("ER", &["ኤርትራ", "إرتريا", "Ertra"]),
+    ("ES", &["España"]),
+    ("ET", &["ኢትዮጵያ", "Ītyōṗṗyā"]),
+    (
+        "EU",
+        &["European Union", "Union européenne", "Europäische Union"],
+    ),
+    ("FI", &["Suomi", "Finland"]),
+    ("FJ", &["Fiji", "Viti", "फ़िजी"]),
+    ("FK", &["Falkland Islands"]),
+    ("FM", &["Micronesia"]),
+    ("FO", &["Føroyar", "Færøerne"]),
+    ("FR", &["France"]),
+    ("GA", &["Gabon"]),
+    ("GB", &["United Kingdom"]),
+    ("GD", &["Grenada"]),
+    ("GE", &["საქართველო", "Sakartvelo"]),
+    ("GF", &["Guyane"]),
+    ("GG", &["Guernsey"]),
+    ("GH", &["Ghana"]),
+    ("GI", &["Gibraltar"]),
+    ("GL", &["Kalaallit Nunaat", "Grønland"]),
+    ("GM", &["The Gambia"]),
+    ("GN", &["Guinée"]),
+    ("GP", &["Guadeloupe"]),
+    (
+        "GQ",
+        &[
+            "Guinea Ecuatorial",
+            "Guinée équatoriale",
+            "Guiné Equatorial",
+        ],
+    ),
+    ("GR", &["Ελλάδα", "Elláda"]),
+    ("GS", &["South Georgia and the South Sandwich Islands"]),
+    ("GT", &["Guatemala"]),
+    ("GU", &["Guam", "Guåhån"]),
+    ("GW", &["Guiné-Bissau"]),
+    ("GY", &["Guyana"]),
+    ("HK", &["香港", "Hong Kong"]),
+    ("HM", &["Heard Island and McDonald Islands"]),
+    ("HN", &["Honduras"]),
+    ("HR", &["Hrvatska"]),
+    ("HT", &["Haïti", "Ayiti"]),
+    ("HU", &["Magyarország"]),
+    ("IC", &["Canarias"]),
+    ("ID", &["Indonesia"]),
+    ("IE", &["Éire", "Ireland"]),
+    ("IL", &["ישראל", "إسرائيل", "Yisraʼel"]),
+    ("IM", &["Isle of Man", "Ellan Vannin"]),
+    ("IN", &["भारत", "Bhārat", "India"]),
+    ("IO", &["British Indian Ocean Territory"]),
+    ("IQ", &["العراق", "عێراق", "Al-ʿIrāq"]),
+    ("IR", &["ایران", "Īrān"]),
+    ("IS", &["Ísland"]),
+    ("IT", &["Italia"]),
+    ("JE", &["Jersey", "Jèrri"]),
+    ("JM", &["Jamaica"]),
+    ("JO", &["الأردن", "Al-Urdun"]),
+    ("JP", &["日本", "Nihon", "Nippon"]),
+    ("KE", &["Kenya"]),
+    ("KG", &["Кыргызстан", "Kyrgyzstan"]),
+    ("KH", &["កម្ពុជា", "Kâmpŭchéa"]),
+    ("KI", &["Kiribati"]),
+    ("KM", &["Komori", "Comores", "جزر القمر"]),
+    ("KN", &["Saint Kitts and Nevis"]),
+    ("KP", &["조선", "Chosŏn"]),
+    ("KR", &["대한민국", "한국", "Hanguk", "Daehan Minguk"]),
+    ("KW", &["الكويت", "Al-Kuwayt"]),
+    ("KY", &["Cayman Islands"]),
+    ("KZ", &["Қазақстан", "Казахстан", "Qazaqstan"]),
+    ("LA", &["ລາວ", "Lao"]),
+    ("LB", &["لبنان", "Lubnān"]),
+    ("LC", &["Saint Lucia"]),
+    ("LI", &["Liechtenstein"]),
+    ("LK", &["ශ්‍රී ලංකාව", "இலங்கை", "Sri Lanka"]),
+    ("LR", &["Liberia"]),
+    ("LS", &["Lesotho"]),
+    ("LT", &["Lietuva"]),
+    ("LU", &["Lëtzebuerg", "Luxembourg", "Luxemburg"]),
+    ("LV", &["Latvija"]),
+    ("LY", &["ليبيا", "Lībiyā"]),
+    ("MA", &["المغرب", "ⵍⵎⵖⵔⵉⴱ", "Al-Maghrib"]),
+    ("MC", &["Monaco"]),
+    ("MD", &["Moldova"]),
+    ("ME", &["Crna Gora", "Црна Гора"]),
+    ("MF", &["Saint-Martin"]),
+    ("MG", &["Madagasikara", "Madagascar"]),
+    ("MH", &["Aolepān Aorōkin M̧ajeļ", "Marshall Islands"]),
+    ("MK", &["Северна Македонија", "Severna Makedonija"]),
+    ("ML", &["Mali"]),
+    ("MM", &["မြန်မာ", "Myanma"]),
+    ("MN", &["Монгол Улс", "Mongol Uls"]),
+    ("MO", &["澳門", "Macau"]),
+    ("MP", &["Northern Mariana Islands"]),
+    ("MQ", &["Martinique"]),
+    ("MR", &["موريتانيا", "Mauritanie"]),
+    ("MS", &["Montserrat"]),
+    ("MT", &["Malta"]),
+    ("MU", &["Mauritius", "Maurice", "Moris"]),
+    ("MV", &["ދިވެހިރާއްޖެ", "Dhivehi Raajje"]),
+    ("MW", &["Malaŵi"]),
+    ("MX", &["México"]),
+    ("MY", &["Malaysia"]),
+    ("MZ", &["Moçambique"]),
+    ("NA", &["Namibia"]),
+    ("NC", &["Nouvelle-Calédonie"]),
+    ("NE", &["Niger"]),
+    ("NF", &["Norfolk Island"]),
+    ("NG", &["Nigeria"]),
+    ("NI", &["Nicaragua"]),
+    ("NL", &["Nederland"]),
+    ("NO", &["Norge", "Noreg", "Norga"]),
+    ("NP", &["नेपाल", "Nepāl"]),
+    ("NR", &["Naoero", "Nauru"]),
+    ("NU", &["Niuē"]),
+    ("NZ", &["New Zealand", "Aotearoa"]),
+    ("OM", &["عمان", "ʿUmān"]),
+    ("PA", &["Panamá"]),
+    ("PE", &["Perú", "Piruw"]),
+    ("PF", &["Polynésie française"]),
+    ("PG", &["Papua Niugini", "Papua New Guinea"]),
+    ("PH", &["Pilipinas"]),
+    ("PK", &["پاکستان", "Pākistān"]),
+    ("PL", &["Polska"]),
+    ("PM", &["Saint-Pierre-et-Miquelon"]),
+    ("PN", &["Pitcairn Islands"]),
+    ("PR", &["Puerto Rico"]),
+    ("PS", &["فلسطين", "Filasṭīn"]),
+    ("PT", &["Portugal"]),
+    ("PW", &["Belau", "Palau"]),
+    ("PY", &["Paraguay", "Paraguái"]),
+    ("QA", &["قطر", "Qaṭar"]),
+    ("RE", &["La Réunion"]),
+    ("RO", &["România"]),
+    ("RS", &["Србија", "Srbija"]),
+    ("RU", &["Россия", "Rossiya"]),
+    ("RW", &["Rwanda"]),
+    (
+        "SA",
+        &["المملكة العربية السعودية", "السعودية", "As-Suʿūdiyya"],
+    ),
+    ("SB", &["Solomon Islands"]),
+    ("SC", &["Seychelles", "Sesel"]),
+    ("SD", &["السودان", "As-Sūdān"]),
+    ("SE", &["Sverige"]),
+    ("SG", &["Singapore", "Singapura", "新加坡", "சிங்கப்பூர்"]),
+    ("SH", &["Saint Helena"]),
+    ("SI", &["Slovenija"]),
+    ("SJ", &["Svalbard og Jan Mayen"]),
+    ("SK", &["Slovensko"]),
+    ("SL", &["Sierra Leone"]),
+    ("SM", &["San Marino"]),
+    ("SN", &["Sénégal"]),
+    ("SO", &["Soomaaliya", "الصومال"]),
+    ("SR", &["Suriname"]),
+    ("SS", &["South Sudan"]),
+    ("ST", &["São Tomé e Príncipe"]),
+    ("SV", &["El Salvador"]),
+    ("SX", &["Sint Maarten"]),
+    ("SY", &["سوريا", "Sūriyā"]),
+    ("SZ", &["eSwatini"]),
+    ("TA", &["Tristan da Cunha"]),
+    ("TC", &["Turks and Caicos Islands"]),
+    ("TD", &["Tchad", "تشاد"]),
+    ("TF", &["Terres australes et antarctiques françaises"]),
+    ("TG", &["Togo"]),
+    ("TH", &["ประเทศไทย", "Prathet Thai"]),
+    ("TJ", &["Тоҷикистон", "Tojikiston"]),
+    ("TK", &["Tokelau"]),
+    ("TL", &["Timor-Leste", "Timor Lorosaʼe"]),
+    ("TM", &["Türkmenistan"]),
+    ("TN", &["تونس", "Tūnis"]),
+    ("TO", &["Tonga"]),
+    ("TR", &["Türkiye"]),
+    ("TT", &["Trinidad and Tobago"]),
+    ("TV", &["Tuvalu"]),
+    ("TW", &["臺灣", "台灣", "Taiwan"]),
+    ("TZ", &["Tanzania"]),
+    ("UA", &["Україна", "Ukraina"]),
+    ("UG", &["Uganda"]),
+    ("UM", &["United States Minor Outlying Islands"]),
+    (
+        "UN",
+        &[
+            "United Nations",
+            "Nations Unies",
+            "Naciones Unidas",
+            "Организация Объединённых Наций",
+            "联合国",
+            "الأمم المتحدة",
+        ],
+    ),
+    ("US", &["United States"]),
+    ("UY", &["Uruguay"]),
+    ("UZ", &["Oʻzbekiston", "Ўзбекистон"]),
+    ("VA", &["Città del Vaticano", "Civitas Vaticana"]),
+    ("VC", &["Saint Vincent and the Grenadines"]),
+    ("VE", &["Venezuela"]),
+    ("VG", &["British Virgin Islands"]),
+    ("VI", &["United States Virgin Islands"]),
+    ("VN", &["Việt Nam"]),
+    ("VU", &["Vanuatu"]),
+    ("WF", &["Wallis-et-Futuna", "ʻUvea mo Futuna"]),
+    ("WS", &["Sāmoa"]),
+    ("XK", &["Kosova", "Косово"]),
+    ("YE", &["اليمن", "Al-Yaman"]),
+    ("YT", &["Mayotte"]),
+    (
+        "ZA",
+        &[
+            "South Africa",
+            "Suid-Afrika",
+            "iNingizimu Afrika",
+            "uMzantsi Afrika",
+            "Afrika Borwa",
+        ],
+    ),
+    ("ZM", &["Zambia"]),
+    ("ZW", &["Zimbabwe"]),
+];
@@ -1,7 +1,7 @@
 //! # country-emoji
 //!
-//! country-emoji provides conversions between country names, ISO 3166-1 alpha-2 codes,
-//! and Unicode flag emojis.
+//! country-emoji provides conversions between country names, ISO 3166-1 alpha-2, alpha-3,
+//! and numeric codes, and Unicode flag emojis.
 //!
 //! The crate supports exact lookups by code or flag emoji, plus alias-aware and normalized
 //! matching for country names.
@@ -10,9 +10,17 @@
 //!
 //! - Exact lookups backed by precomputed tables for codes, flags, and normalized names
 //! - Country-name normalization for case, whitespace, diacritics, and common abbreviations
-//! - Alias, formal-name, and fuzzy matching for text input
+//! - Alias, formal-name, and fuzzy matching for text input, including common misspellings
+//!   and native names
 //! - Bidirectional conversions between names, ISO codes, and flag emojis
+//! - Prefix autocomplete for country pickers
+//! - Locale-aware alphabetical sorting of country names for dropdowns
+//! - Flag extraction and replacement, and country-name detection, in arbitrary text
+//! - Subdivision flags for England, Scotland, and Wales, and ISO 3166-2 subdivisions of
+//!   Brazil, Canada, Germany, and the United States behind an optional feature
 //! - Borrowed string returns for code and name APIs where possible
+//! - Localized country names for Arabic, Chinese, French, German, Japanese, Portuguese,
+//!   Russian, Spanish, and Swedish behind optional features, recognized by name matching
 //!
 //! ## Quick Start
 //!
@@ -69,12 +77,61 @@
 //! assert_eq!(name_to_code("Spain"), Some("ES"));
 //! assert_eq!(code_to_name("BR"), Some("Brazil"));
 //! ```
+//!
+//! ## ISO 3166-1 Alpha-3 Codes
+//!
+//! Alpha-3 codes convert to and from alpha-2 codes and are accepted by [`code`], [`flag`],
+//! and [`name`]:
+//!
+//! ```rust
+//! use country_emoji::{alpha2_to_alpha3, alpha3_to_alpha2, code, name};
+//!
+//! assert_eq!(alpha2_to_alpha3("DE"), Some("DEU"));
+//! assert_eq!(alpha3_to_alpha2("USA"), Some("US"));
+//! assert_eq!(code("DEU"), Some("DE"));
+//! assert_eq!(name("BRA"), Some("Brazil"));
+//! ```
+//!
+//! ## ISO 3166-1 Numeric Codes
+//!
+//! Zero-padded numeric codes are supported in the same way:
+//!
+//! ```rust
+//! use country_emoji::{code, code_to_numeric, flag, numeric_to_code};
+//!
+//! assert_eq!(code_to_numeric("BR"), Some("076"));
+//! assert_eq!(numeric_to_code("076"), Some("BR"));
+//! assert_eq!(code("076"), Some("BR"));
+//! assert_eq!(flag("076"), Some("🇧🇷".to_string()));
+//! ```
 
 mod countries;
-use countries::{country_code_index_from_bytes, COUNTRIES, COUNTRIES_BY_CODE_INDEX};
+mod country_code;
+mod endonyms;
+mod list;
+mod locale;
+mod matcher;
+mod registry;
+mod subdivision;
+mod suggest;
+mod text;
+use countries::{
+    alpha3_code_index_from_bytes, country_code_index_from_bytes, numeric_code_index_from_bytes,
+    COUNTRIES, COUNTRIES_BY_ALPHA3_INDEX, COUNTRIES_BY_CODE_INDEX, COUNTRIES_BY_NUMERIC_INDEX,
+};
+pub use country_code::{CountryCode, ParseCountryCodeError};
+use endonyms::ENDONYMS;
+pub use list::{all_countries, sorted_by_name, CountryList, CountryOrder};
+pub use locale::{Locale, ParseLocaleError};
+use matcher::DEFAULT_MATCHER;
+pub use matcher::{Matcher, MatcherBuilder};
 use once_cell::sync::Lazy;
+pub use registry::CountryRegistry;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use registry::{DataFormat, LoadError, LoadMode};
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+pub use text::{codes_to_flags, find_countries, find_flags, flags_to_names, replace_flags};
 use unidecode::unidecode;
 
 // Unicode regional indicator symbols start at U+1F1E6, which corresponds to 'A'.
@@ -108,6 +165,10 @@ const GOVERNMENT_SUFFIXES: &[&str; 5] = &[
 const AMBIGUOUS_STRIPPED_TERMS: &[&str; 6] =
     &["korea", "guinea", "congo", "virgin", "samoa", "sudan"];
 
+// Fuzzy candidates scoring within this margin of the best candidate are reported as
+// equally plausible when a lookup fails.
+const AMBIGUITY_MARGIN: f32 = 0.05;
+
 const GENERIC_WORDS: &[&str; 29] = &[
     "united",
     "republic",
@@ -140,10 +201,206 @@ const GENERIC_WORDS: &[&str; 29] = &[
     "principe",
 ];
 
-pub(crate) type Country = (&'static str, &'static [&'static str]);
+/// The ISO 3166-1 assignment status of a code in the dataset.
+///
+/// Most entries are officially assigned. The remaining entries are kept because they have
+/// flag emojis or are still used in practice, but they are not ISO 3166-1 countries.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{code_status, CodeStatus};
+///
+/// assert_eq!(code_status("DE"), Some(CodeStatus::Official));
+/// assert_eq!(code_status("EU"), Some(CodeStatus::ExceptionallyReserved));
+/// assert!(!CodeStatus::UserAssigned.is_official());
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeStatus {
+    /// Officially assigned to a country or territory, such as `DE` or `PR`.
+    Official,
+    /// Reserved at the request of a national ISO member or international body, such as `EU`,
+    /// `UN`, or `AC`.
+    ExceptionallyReserved,
+    /// Reserved for a deleted code during a transition period, such as `AN`.
+    TransitionallyReserved,
+    /// Free for user assignment and adopted by convention, such as `XK` for Kosovo.
+    UserAssigned,
+}
+
+impl CodeStatus {
+    /// Returns whether the code is officially assigned.
+    pub fn is_official(self) -> bool {
+        self == CodeStatus::Official
+    }
+}
+
+/// A country or territory entry from the built-in dataset.
+///
+/// Values are obtained through lookups such as [`country`] or [`code_to_country`] and are
+/// always backed by static data, so they are cheap to copy and pass around.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::country;
+///
+/// let germany = country("Germany").unwrap();
+/// assert_eq!(germany.code(), "DE");
+/// assert_eq!(germany.name(), "Germany");
+/// assert_eq!(germany.flag(), "🇩🇪");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Country {
+    code: &'static str,
+    alpha3: Option<&'static str>,
+    numeric: Option<&'static str>,
+    names: &'static [&'static str],
+    demonyms: &'static [&'static str],
+    status: CodeStatus,
+    territory: bool,
+}
+
+impl Country {
+    /// Builds a dataset entry. An empty `alpha3` or `numeric` marks an entry without that
+    /// code.
+    pub(crate) const fn new(
+        code: &'static str,
+        alpha3: &'static str,
+        numeric: &'static str,
+        names: &'static [&'static str],
+        demonyms: &'static [&'static str],
+    ) -> Self {
+        Self {
+            code,
+            alpha3: non_empty_code(alpha3),
+            numeric: non_empty_code(numeric),
+            names,
+            demonyms,
+            status: CodeStatus::Official,
+            territory: false,
+        }
+    }
+
+    pub(crate) const fn with_status(mut self, status: CodeStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub(crate) const fn territory(mut self) -> Self {
+        self.territory = true;
+        self
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code as a [`CountryCode`].
+    pub fn country_code(&self) -> CountryCode {
+        self.code
+            .parse()
+            .expect("every dataset entry has a CountryCode")
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code, if one is assigned or reserved for this entry.
+    pub fn alpha3(&self) -> Option<&'static str> {
+        self.alpha3
+    }
+
+    /// Returns the zero-padded ISO 3166-1 numeric code, if one is assigned for this entry.
+    pub fn numeric(&self) -> Option<&'static str> {
+        self.numeric
+    }
+
+    /// Returns the preferred display name.
+    pub fn name(&self) -> &'static str {
+        self.names[0]
+    }
+
+    /// Returns the display name in the given language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use country_emoji::{country, Locale};
+    ///
+    /// assert_eq!(country("JP").unwrap().name_in(Locale::En), "Japan");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        locale::localized_name(self.code, locale).unwrap_or(self.name())
+    }
+
+    /// Returns the names of this entry in its own official languages, such as
+    /// `["Suomi", "Finland"]` for Finland. Names in non-Latin scripts are followed by a common
+    /// Latin transliteration.
+    pub fn endonyms(&self) -> &'static [&'static str] {
+        endonyms::endonyms(self.code).unwrap_or_default()
+    }
+
+    /// Returns the alternative names recognized for this entry, excluding the preferred name.
+    pub fn aliases(&self) -> &'static [&'static str] {
+        &self.names[1..]
+    }
+
+    /// Returns the preferred demonym, such as `"German"` for Germany, if the entry has one.
+    pub fn demonym(&self) -> Option<&'static str> {
+        self.demonyms.first().copied()
+    }
+
+    /// Returns every demonym recognized for this entry, preferred first.
+    pub fn demonyms(&self) -> &'static [&'static str] {
+        self.demonyms
+    }
+
+    /// Returns the Unicode flag emoji.
+    pub fn flag(&self) -> String {
+        code_to_flag_emoji(self.code)
+    }
+
+    /// Returns the ISO 3166-1 assignment status of the code.
+    pub fn status(&self) -> CodeStatus {
+        self.status
+    }
+
+    /// Returns whether this entry is a territory rather than a sovereign state, such as
+    /// Puerto Rico, Greenland, Hong Kong, or Antarctica.
+    ///
+    /// Territories often have officially assigned codes of their own, so this is independent
+    /// of [`status`](Self::status). Unions and organizations such as `EU` and `UN` are not
+    /// territories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use country_emoji::country;
+    ///
+    /// assert!(country("PR").unwrap().is_territory());
+    /// assert!(!country("DK").unwrap().is_territory());
+    /// assert!(!country("EU").unwrap().is_territory());
+    /// ```
+    pub fn is_territory(&self) -> bool {
+        self.territory
+    }
+
+    pub(crate) fn names(&self) -> &'static [&'static str] {
+        self.names
+    }
+}
+
+const fn non_empty_code(code: &'static str) -> Option<&'static str> {
+    if code.is_empty() {
+        None
+    } else {
+        Some(code)
+    }
+}
 
 use std::sync::Arc;
 
+#[derive(Clone)]
 struct NormalizedNameData {
     text: Arc<str>,
     words: Box<[Arc<str>]>,
@@ -157,13 +414,19 @@ type WordCountryIndex = HashMap<Arc<str>, Vec<usize>>;
 fn register_country_names(
     map: &mut CountryNameMap,
     word_index: &mut WordCountryIndex,
-    names: &'static [&'static str],
+    generic_words: &[&str],
+    names: &[&str],
     code: &'static str,
     country_index: usize,
 ) -> (NormalizedNameData, Vec<NormalizedNameData>) {
     // Prepare normalized data
     let primary_normalized_text: Arc<str> = Arc::from(normalize_text(names[0]));
-    index_variant_words(word_index, &primary_normalized_text, country_index);
+    index_variant_words(
+        word_index,
+        generic_words,
+        &primary_normalized_text,
+        country_index,
+    );
     let primary_normalized = build_normalized_name_data(primary_normalized_text.clone());
 
     // Note: We insert primary_normalized_text in the loop below as well, but we need the
@@ -178,7 +441,7 @@ fn register_country_names(
                 variant.text.as_ref() == normalized_arc.as_ref()
             })
         {
-            index_variant_words(word_index, &normalized_arc, country_index);
+            index_variant_words(word_index, generic_words, &normalized_arc, country_index);
             all_variants.push(build_normalized_name_data(normalized_arc.clone()));
         }
 
@@ -188,7 +451,7 @@ fn register_country_names(
         if let Some(articleless_variant) = remove_articles(normalized_arc.as_ref()) {
             let articleless_arc: Arc<str> = Arc::from(articleless_variant.as_str());
             map.entry(articleless_arc.clone()).or_insert(code);
-            index_variant_words(word_index, &articleless_arc, country_index);
+            index_variant_words(word_index, generic_words, &articleless_arc, country_index);
         }
 
         // Add lowercased name to map
@@ -198,44 +461,230 @@ fn register_country_names(
         for variant in strip_government_patterns(normalized_arc.as_ref()) {
             let variant_arc: Arc<str> = Arc::from(variant.as_str());
             map.entry(variant_arc.clone()).or_insert(code);
-            index_variant_words(word_index, &variant_arc, country_index);
+            index_variant_words(word_index, generic_words, &variant_arc, country_index);
         }
     }
 
     (primary_normalized, all_variants)
 }
 
-static COUNTRIES_DATA: Lazy<(CountryNameMap, Vec<NormalizedCountryData>, WordCountryIndex)> =
-    Lazy::new(|| {
-        let mut map: CountryNameMap = HashMap::new();
-        let mut normalized_countries = Vec::with_capacity(COUNTRIES.len());
-        let mut word_index: WordCountryIndex = HashMap::new();
+/// Normalized names, their derived variants, and the word index used to narrow fuzzy
+/// matching, for one set of countries.
+#[derive(Clone)]
+pub(crate) struct NameIndex {
+    names: CountryNameMap,
+    demonyms: CountryNameMap,
+    shared_names: HashMap<Arc<str>, Vec<&'static str>>,
+    countries: Vec<NormalizedCountryData>,
+    words: WordCountryIndex,
+    // Normalized demonyms and their word index, by country position, scored in fuzzy matching
+    // only when the matcher accepts demonyms.
+    demonym_variants: Vec<Vec<NormalizedNameData>>,
+    demonym_words: WordCountryIndex,
+    all_indices: Vec<usize>,
+    generic_words: &'static [&'static str],
+}
+
+impl NameIndex {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            names: HashMap::new(),
+            demonyms: HashMap::new(),
+            shared_names: HashMap::new(),
+            countries: Vec::with_capacity(capacity),
+            words: HashMap::new(),
+            demonym_variants: Vec::with_capacity(capacity),
+            demonym_words: HashMap::new(),
+            all_indices: Vec::with_capacity(capacity),
+            generic_words: GENERIC_WORDS,
+        }
+    }
+
+    /// Replaces the words that are too generic to identify an entry on their own.
+    ///
+    /// Call this before inserting names, since generic words are left out of the word index.
+    pub(crate) fn with_generic_words(mut self, generic_words: &'static [&'static str]) -> Self {
+        self.generic_words = generic_words;
+        self
+    }
+
+    pub(crate) fn is_generic(&self, word: &str) -> bool {
+        self.generic_words.contains(&word)
+    }
+
+    /// Returns the countries sharing a localized name, given in normalized form, with the
+    /// country it otherwise resolves to.
+    pub(crate) fn shared_name_codes(&self, normalized: &str) -> &[&'static str] {
+        self.shared_names.get(normalized).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the codes of the entries with a name containing all the given words in order,
+    /// such as both Carolinas for `["carolina"]`.
+    pub(crate) fn codes_containing<'a>(
+        &'a self,
+        words: &'a [&str],
+    ) -> impl Iterator<Item = &'static str> + 'a {
+        self.countries
+            .iter()
+            .filter(move |(primary, variants, _)| {
+                !words.is_empty()
+                    && std::iter::once(primary).chain(variants).any(|name| {
+                        name.words.windows(words.len()).any(|window| {
+                            window
+                                .iter()
+                                .zip(words)
+                                .all(|(left, right)| left.as_ref() == *right)
+                        })
+                    })
+            })
+            .map(|&(_, _, code)| code)
+    }
+
+    /// Registers a country and its names. The first name is the preferred one.
+    ///
+    /// Explicit names use `insert` (overwrite) and derived variants use `or_insert` (no
+    /// overwrite), so explicit names always take precedence regardless of insertion order.
+    pub(crate) fn insert_country(&mut self, code: &'static str, names: &[&str]) {
+        let country_index = self.countries.len();
+        let (primary_normalized, all_variants) = register_country_names(
+            &mut self.names,
+            &mut self.words,
+            self.generic_words,
+            names,
+            code,
+            country_index,
+        );
+
+        self.countries
+            .push((primary_normalized, all_variants, code));
+        self.demonym_variants.push(Vec::new());
+        self.all_indices.push(country_index);
+    }
 
-        // Single Pass: Insert explicit names, build normalized data, and insert derived variants
-        // Explicit names use `insert` (overwrite), Derived use `or_insert` (no overwrite)
-        // This ensures explicit names always take precedence, regardless of country order.
-        for country in COUNTRIES.iter() {
-            let code = country.0;
-            let names = country.1;
-            let country_index = normalized_countries.len();
+    /// Registers an additional name for an already inserted country.
+    pub(crate) fn insert_alias(&mut self, country_index: usize, alias: &str) {
+        let code = self.countries[country_index].2;
+        let (alias_normalized, _) = register_country_names(
+            &mut self.names,
+            &mut self.words,
+            self.generic_words,
+            &[alias],
+            code,
+            country_index,
+        );
+
+        let (primary_normalized, all_variants, _) = &mut self.countries[country_index];
+        if alias_normalized.text != primary_normalized.text
+            && !all_variants
+                .iter()
+                .any(|variant| variant.text == alias_normalized.text)
+        {
+            all_variants.push(alias_normalized);
+        }
+    }
 
-            let (primary_normalized, all_variants) =
-                register_country_names(&mut map, &mut word_index, names, code, country_index);
+    /// Registers the endonyms and the names of every enabled locale for the countries already
+    /// in the index. Endonyms take precedence over translations.
+    ///
+    /// Call this after all countries are inserted so that localized names can never shadow
+    /// one of their names.
+    pub(crate) fn insert_localized_names(&mut self) {
+        let codes: HashSet<&'static str> = self.countries.iter().map(|country| country.2).collect();
+        let endonyms = ENDONYMS
+            .iter()
+            .flat_map(|&(code, names)| names.iter().map(move |&name| (code, name)));
+        let translations = Locale::ALL
+            .iter()
+            .flat_map(|locale| locale.names().unwrap_or_default().iter().copied());
+
+        for (code, name) in endonyms.chain(translations) {
+            if let Some(&code) = codes.get(code) {
+                self.insert_localized_name(code, name);
+            }
+        }
+    }
 
-            normalized_countries.push((primary_normalized, all_variants, code));
+    /// Registers a demonym of a country.
+    ///
+    /// Demonyms are kept apart from names so that matchers can opt out of them, and only match
+    /// exactly or after normalization. A demonym shared by several countries, such as
+    /// "Dominican", resolves to the last of them, like a shared name would.
+    pub(crate) fn insert_demonym(&mut self, code: &'static str, demonym: &str) {
+        let normalized: Arc<str> = Arc::from(normalize_text(demonym));
+        self.demonyms
+            .insert(Arc::from(demonym.to_lowercase()), code);
+        self.demonyms.insert(normalized.clone(), code);
+
+        let Some(country_index) = self.countries.iter().position(|country| country.2 == code)
+        else {
+            return;
+        };
+        let variants = &mut self.demonym_variants[country_index];
+        if normalized.is_empty() || variants.iter().any(|variant| variant.text == normalized) {
+            return;
         }
+        index_variant_words(
+            &mut self.demonym_words,
+            self.generic_words,
+            &normalized,
+            country_index,
+        );
+        variants.push(build_normalized_name_data(normalized));
+    }
 
-        (map, normalized_countries, word_index)
-    });
+    /// Registers a name in another language for exact and normalized lookups.
+    ///
+    /// Unlike aliases, localized names never replace a name already in the index and stay out
+    /// of fuzzy scoring, where compounds such as the German "Südkorea" would otherwise make
+    /// "Korea" resolve. Normalized forms that are generic or ambiguous on their own, such as
+    /// the Spanish "Congo" or the Icelandic "Ísland", only match with their exact spelling.
+    ///
+    /// A localized name that is a shared term or already names another country, as the
+    /// Spanish "Congo" for CG does, is also recorded as shared so that lookups resolving it
+    /// to another country can report the ambiguity.
+    fn insert_localized_name(&mut self, code: &'static str, name: &str) {
+        let normalized = normalize_text(name);
+        if normalized.is_empty() {
+            return;
+        }
 
-static COUNTRIES_NAME_MAP: Lazy<&HashMap<Arc<str>, &'static str>> = Lazy::new(|| &COUNTRIES_DATA.0);
+        if AMBIGUOUS_STRIPPED_TERMS.contains(&normalized.as_str())
+            || lookup_country_name(self, &normalized).is_some_and(|named| named != code)
+        {
+            let codes = self
+                .shared_names
+                .entry(Arc::from(normalized.as_str()))
+                .or_default();
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
 
-static NORMALIZED_COUNTRIES: Lazy<&Vec<NormalizedCountryData>> = Lazy::new(|| &COUNTRIES_DATA.1);
+        let lowercase = name.to_lowercase();
+        if lowercase != normalized {
+            self.names.entry(Arc::from(lowercase)).or_insert(code);
+        }
 
-static WORD_COUNTRY_INDEX: Lazy<&WordCountryIndex> = Lazy::new(|| &COUNTRIES_DATA.2);
+        if !is_too_generic(&normalized) && !AMBIGUOUS_STRIPPED_TERMS.contains(&normalized.as_str())
+        {
+            self.names.entry(Arc::from(normalized)).or_insert(code);
+        }
+    }
+}
 
-static ALL_COUNTRY_INDICES: Lazy<Vec<usize>> =
-    Lazy::new(|| (0..NORMALIZED_COUNTRIES.len()).collect());
+static COUNTRIES_INDEX: Lazy<NameIndex> = Lazy::new(|| {
+    let mut index = NameIndex::with_capacity(COUNTRIES.len());
+    for country in COUNTRIES.iter() {
+        index.insert_country(country.code(), country.names());
+    }
+    for country in COUNTRIES.iter() {
+        for demonym in country.demonyms() {
+            index.insert_demonym(country.code(), demonym);
+        }
+    }
+    index.insert_localized_names();
+    index
+});
 
 fn trim_upper(text: &str) -> String {
     text.trim().to_ascii_uppercase()
@@ -344,28 +793,40 @@ fn remove_articles(text: &str) -> Option<String> {
 }
 
 fn strip_government_patterns(normalized: &str) -> Vec<String> {
+    government_pattern_variants(normalized, true, true)
+}
+
+fn government_pattern_variants(
+    normalized: &str,
+    reverse_commas: bool,
+    strip_titles: bool,
+) -> Vec<String> {
     let mut variants = vec![normalized.to_string()];
 
-    if normalized.contains(',') {
+    if reverse_commas && normalized.contains(',') {
         let mut name_parts: Vec<&str> = normalized.split(", ").collect();
         name_parts.reverse();
         let reversed_name = name_parts.join(" ");
         if !variants.contains(&reversed_name) {
             variants.push(reversed_name.clone());
 
-            let reversed_variants = strip_government_patterns_internal(&reversed_name);
-            for variant in reversed_variants {
-                if !variants.contains(&variant) {
-                    variants.push(variant);
+            if strip_titles {
+                let reversed_variants = strip_government_patterns_internal(&reversed_name);
+                for variant in reversed_variants {
+                    if !variants.contains(&variant) {
+                        variants.push(variant);
+                    }
                 }
             }
         }
     }
 
-    let pattern_variants = strip_government_patterns_internal(normalized);
-    for variant in pattern_variants {
-        if !variants.contains(&variant) {
-            variants.push(variant);
+    if strip_titles {
+        let pattern_variants = strip_government_patterns_internal(normalized);
+        for variant in pattern_variants {
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
         }
     }
 
@@ -406,12 +867,13 @@ fn push_variant_if_valid(variants: &mut Vec<String>, stripped: &str, original: &
 
 fn index_variant_words(
     word_index: &mut WordCountryIndex,
+    generic_words: &[&str],
     variant: &Arc<str>,
     country_index: usize,
 ) {
     for word in variant
         .split_whitespace()
-        .filter(|word| !is_too_generic(word))
+        .filter(|word| !generic_words.contains(word))
     {
         let entry = word_index.entry(Arc::from(word)).or_default();
 
@@ -421,17 +883,25 @@ fn index_variant_words(
     }
 }
 
-fn collect_candidate_countries(input_words: &[&str]) -> Option<Vec<usize>> {
-    let mut seen = vec![false; NORMALIZED_COUNTRIES.len()];
+fn collect_candidate_countries(
+    index: &NameIndex,
+    matcher: &Matcher,
+    input_words: &[&str],
+) -> Option<Vec<usize>> {
+    let mut seen = vec![false; index.countries.len()];
     let mut candidates = Vec::new();
 
     for word in input_words
         .iter()
         .copied()
-        .filter(|word| !is_too_generic(word))
+        .filter(|word| !matcher.is_generic(index, word))
     {
-        let indices = WORD_COUNTRY_INDEX.get(word)?;
-        for &country_index in indices.iter() {
+        let names = index.words.get(word);
+        let demonyms = index.demonym_words.get(word).filter(|_| matcher.demonyms);
+        if names.is_none() && demonyms.is_none() {
+            return None;
+        }
+        for &country_index in names.into_iter().chain(demonyms).flatten() {
             if !seen[country_index] {
                 seen[country_index] = true;
                 candidates.push(country_index);
@@ -447,32 +917,73 @@ fn collect_candidate_countries(input_words: &[&str]) -> Option<Vec<usize>> {
 }
 
 #[inline]
-fn lookup_country_name(name: &str) -> Option<&'static str> {
-    COUNTRIES_NAME_MAP.get(name).copied()
+fn lookup_country_name(index: &NameIndex, name: &str) -> Option<&'static str> {
+    index.names.get(name).copied()
+}
+
+fn demonym_match(index: &NameIndex, trimmed_input: &str) -> Option<&'static str> {
+    index
+        .demonyms
+        .get(trimmed_input.to_lowercase().as_str())
+        .or_else(|| index.demonyms.get(normalize_text(trimmed_input).as_str()))
+        .copied()
 }
 
 #[inline]
-fn direct_name_match(trimmed_input: &str) -> Option<&'static str> {
-    lookup_country_name(&trimmed_input.to_lowercase())
+fn direct_name_match(index: &NameIndex, trimmed_input: &str) -> Option<&'static str> {
+    lookup_country_name(index, &trimmed_input.to_lowercase())
 }
 
-fn normalized_name_match(normalized_input: &str) -> Option<&'static str> {
-    lookup_country_name(normalized_input).or_else(|| {
-        strip_government_patterns(normalized_input)
-            .into_iter()
-            .find_map(|variant| lookup_country_name(variant.as_str()))
-    })
+fn government_pattern_match(
+    index: &NameIndex,
+    matcher: &Matcher,
+    normalized_input: &str,
+) -> Option<&'static str> {
+    government_pattern_variants(
+        normalized_input,
+        matcher.comma_reversal,
+        matcher.government_patterns,
+    )
+    .into_iter()
+    .find_map(|variant| lookup_country_name(index, variant.as_str()))
+}
+
+// Matches demonyms behind articles and government titles, as in "the French".
+fn government_pattern_demonym_match(
+    index: &NameIndex,
+    matcher: &Matcher,
+    normalized_input: &str,
+) -> Option<&'static str> {
+    government_pattern_variants(
+        normalized_input,
+        matcher.comma_reversal,
+        matcher.government_patterns,
+    )
+    .iter()
+    .skip(1)
+    .find_map(|variant| index.demonyms.get(variant.as_str()).copied())
+}
+
+fn is_official_code(code: &str) -> bool {
+    get_by_code(code).is_some_and(|country| country.status().is_official())
 }
 
 #[inline]
-fn should_reject_fuzzy_match(input_words: &[&str]) -> bool {
-    !input_words.is_empty() && input_words.iter().all(|&word| is_too_generic(word))
+fn should_reject_fuzzy_match(index: &NameIndex, matcher: &Matcher, input_words: &[&str]) -> bool {
+    !input_words.is_empty()
+        && input_words
+            .iter()
+            .all(|&word| matcher.is_generic(index, word))
 }
 
-fn candidate_indices_for(input_words: &[&str]) -> Cow<'static, [usize]> {
-    collect_candidate_countries(input_words)
+fn candidate_indices_for<'a>(
+    index: &'a NameIndex,
+    matcher: &Matcher,
+    input_words: &[&str],
+) -> Cow<'a, [usize]> {
+    collect_candidate_countries(index, matcher, input_words)
         .map(Cow::Owned)
-        .unwrap_or_else(|| Cow::Borrowed(ALL_COUNTRY_INDICES.as_slice()))
+        .unwrap_or_else(|| Cow::Borrowed(index.all_indices.as_slice()))
 }
 
 fn is_too_generic(word: &str) -> bool {
@@ -485,7 +996,93 @@ fn contains_country_word(country_words: &[Arc<str>], word: &str) -> bool {
         .any(|country_word| country_word.as_ref() == word)
 }
 
+// Whether a word names a term shared by several countries, such as "korea", allowing for the
+// typos that fuzzy matching tolerates.
+fn is_ambiguous_term(matcher: &Matcher, word: &str) -> bool {
+    ambiguous_term(matcher, word).is_some()
+}
+
+// Returns the term shared by several countries that a word names, allowing for typos.
+fn ambiguous_term(matcher: &Matcher, word: &str) -> Option<&'static str> {
+    AMBIGUOUS_STRIPPED_TERMS.iter().copied().find(|term| {
+        *term == word
+            || (matcher.typo_tolerance
+                && edit_distance(term.as_bytes(), word.as_bytes()) <= max_typos(word.len()))
+    })
+}
+
+// Longer words tolerate more typos; short words must match exactly because a single edit
+// already turns them into other country names ("iran"/"iraq", "mali"/"bali").
+#[inline]
+fn max_typos(word_len: usize) -> usize {
+    match word_len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+fn word_similarity(
+    index: &NameIndex,
+    matcher: &Matcher,
+    word: &str,
+    country_words: &[Arc<str>],
+) -> Option<f32> {
+    if contains_country_word(country_words, word) {
+        return Some(1.0);
+    }
+
+    if !matcher.typo_tolerance || matcher.is_generic(index, word) {
+        return None;
+    }
+
+    let tolerance = max_typos(word.len());
+    if tolerance == 0 {
+        return None;
+    }
+
+    country_words
+        .iter()
+        .filter(|country_word| country_word.len().abs_diff(word.len()) <= tolerance)
+        .filter_map(|country_word| {
+            let distance = edit_distance(word.as_bytes(), country_word.as_bytes());
+            (distance <= tolerance)
+                .then(|| 1.0 - distance as f32 / word.len().max(country_word.len()) as f32)
+        })
+        .reduce(f32::max)
+}
+
+// Optimal string alignment distance: Levenshtein plus transposition of adjacent bytes.
+// Normalized names are ASCII, so comparing bytes is equivalent to comparing characters.
+fn edit_distance(left: &[u8], right: &[u8]) -> usize {
+    let mut before_previous: Vec<usize> = vec![0; right.len() + 1];
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current: Vec<usize> = vec![0; right.len() + 1];
+
+    for (i, &left_byte) in left.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, &right_byte) in right.iter().enumerate() {
+            let substitution_cost = usize::from(left_byte != right_byte);
+            current[j + 1] = (previous[j] + substitution_cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+
+            if i > 0 && j > 0 && left_byte == right[j - 1] && left[i - 1] == right_byte {
+                current[j + 1] = current[j + 1].min(before_previous[j - 1] + 1);
+            }
+        }
+
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[right.len()]
+}
+
 fn calculate_similarity_score(
+    index: &NameIndex,
+    matcher: &Matcher,
     input: &str,
     input_words: &[&str],
     country_name: &NormalizedNameData,
@@ -520,10 +1117,17 @@ fn calculate_similarity_score(
 
     let country_words = country_name.words.as_ref();
 
-    let intersection = input_words
-        .iter()
-        .filter(|word| contains_country_word(country_words, word))
-        .count();
+    // Words matched through typo tolerance count as partial overlap.
+    let mut intersection = 0;
+    let mut overlap = 0.0f32;
+    let mut has_shared_primary = false;
+    for &word in input_words {
+        if let Some(similarity) = word_similarity(index, matcher, word, country_words) {
+            intersection += 1;
+            overlap += similarity;
+            has_shared_primary |= !matcher.is_generic(index, word);
+        }
+    }
 
     let union = input_words.len() + country_words.len() - intersection;
 
@@ -531,16 +1135,12 @@ fn calculate_similarity_score(
         return 0.0;
     }
 
-    let jaccard_score = intersection as f32 / union as f32;
+    let jaccard_score = overlap / union as f32;
 
     if input_words.len() == 1 && country_words.len() > 1 {
         return jaccard_score * 0.2;
     }
 
-    let has_shared_primary = input_words
-        .iter()
-        .any(|&word| !is_too_generic(word) && contains_country_word(country_words, word));
-
     if !has_shared_primary && intersection > 0 {
         return jaccard_score * 0.1;
     }
@@ -550,16 +1150,31 @@ fn calculate_similarity_score(
 
 #[inline]
 fn score_country(
+    index: &NameIndex,
+    matcher: &Matcher,
     normalized_input: &str,
     input_words: &[&str],
-    country: &NormalizedCountryData,
+    country_index: usize,
 ) -> f32 {
-    let (primary_normalized, all_variants, _) = country;
+    let (primary_normalized, all_variants, _) = &index.countries[country_index];
+    let demonyms: &[NormalizedNameData] = if matcher.demonyms {
+        &index.demonym_variants[country_index]
+    } else {
+        &[]
+    };
 
-    all_variants.iter().fold(
-        calculate_similarity_score(normalized_input, input_words, primary_normalized),
+    all_variants.iter().chain(demonyms).fold(
+        calculate_similarity_score(
+            index,
+            matcher,
+            normalized_input,
+            input_words,
+            primary_normalized,
+        ),
         |best_score, variant| {
             best_score.max(calculate_similarity_score(
+                index,
+                matcher,
                 normalized_input,
                 input_words,
                 variant,
@@ -568,17 +1183,42 @@ fn score_country(
     )
 }
 
-fn best_fuzzy_match(normalized_input: &str, input_words: &[&str]) -> Option<(&'static str, f32)> {
+fn scored_candidates<'a>(
+    index: &'a NameIndex,
+    matcher: &'a Matcher,
+    normalized_input: &'a str,
+    input_words: &'a [&'a str],
+    candidate_indices: Cow<'a, [usize]>,
+) -> impl Iterator<Item = (&'static str, f32)> + 'a {
+    (0..candidate_indices.len()).map(move |position| {
+        let country_index = candidate_indices[position];
+        (
+            index.countries[country_index].2,
+            score_country(index, matcher, normalized_input, input_words, country_index),
+        )
+    })
+}
+
+fn best_fuzzy_match(
+    index: &NameIndex,
+    matcher: &Matcher,
+    normalized_input: &str,
+    input_words: &[&str],
+) -> Option<(&'static str, f32)> {
     let mut best_match = None;
     let mut best_score = 0.0f32;
 
-    for &country_index in candidate_indices_for(input_words).iter() {
-        let country = &NORMALIZED_COUNTRIES[country_index];
-        let score = score_country(normalized_input, input_words, country);
-
+    let candidate_indices = candidate_indices_for(index, matcher, input_words);
+    for (code, score) in scored_candidates(
+        index,
+        matcher,
+        normalized_input,
+        input_words,
+        candidate_indices,
+    ) {
         if score > best_score {
             best_score = score;
-            best_match = Some((country.2, score));
+            best_match = Some((code, score));
 
             if score >= 1.0 {
                 break;
@@ -589,15 +1229,6 @@ fn best_fuzzy_match(normalized_input: &str, input_words: &[&str]) -> Option<(&'s
     best_match
 }
 
-#[inline]
-fn fuzzy_match_threshold(input_words: &[&str]) -> f32 {
-    if input_words.len() == 1 {
-        0.4
-    } else {
-        0.2
-    }
-}
-
 pub(crate) fn code_to_flag_emoji(code: &str) -> String {
     let mut flag = String::new();
     for c in trim_upper(code).chars() {
@@ -640,29 +1271,74 @@ fn check_by_code(code: &str) -> bool {
     get_by_code(code).is_some()
 }
 
-fn get_by_code(code: &str) -> Option<&Country> {
+pub(crate) fn get_by_code(code: &str) -> Option<&'static Country> {
     country_code_index(code).and_then(|index| COUNTRIES_BY_CODE_INDEX[index])
 }
 
+// Resolved ISO 3166-2 codes, such as "GB-SCT", belong to the country named by their prefix.
+fn get_by_resolved_code(code: &str) -> Option<&'static Country> {
+    get_by_code(code.split_once('-').map_or(code, |(country, _)| country))
+}
+
+fn get_by_alpha3(code: &str) -> Option<&'static Country> {
+    alpha3_code_index_from_bytes(code.trim().as_bytes())
+        .and_then(|index| COUNTRIES_BY_ALPHA3_INDEX[index])
+}
+
+fn get_by_numeric(code: &str) -> Option<&'static Country> {
+    numeric_code_index_from_bytes(code.trim().as_bytes())
+        .and_then(|index| COUNTRIES_BY_NUMERIC_INDEX[index])
+}
+
+fn get_by_any_code(code: &str) -> Option<&'static Country> {
+    get_by_code(code)
+        .or_else(|| get_by_alpha3(code))
+        .or_else(|| get_by_numeric(code))
+}
+
+// Codes only take priority over names when written in uppercase, so that short text such as
+// "de", "St", or "can" is still matched as a name.
+fn get_by_code_input(input: &str) -> Option<&'static Country> {
+    let trimmed = input.trim();
+    if trimmed.bytes().any(|byte| byte.is_ascii_lowercase()) {
+        return None;
+    }
+    get_by_any_code(trimmed)
+}
+
+// Subdivisions are only resolved by flag, code, or exact name, never by fuzzy matching.
+fn get_flag_subdivision(input: &str) -> Option<&'static str> {
+    subdivision::flag_to_subdivision_code(input)
+        .or_else(|| subdivision::flag_subdivision_by_code(input))
+        .or_else(|| subdivision::flag_subdivision_by_name(input))
+}
+
 fn check_by_flag(flag: &str) -> bool {
     get_by_flag(flag).is_some()
 }
 
-fn get_by_flag(flag: &str) -> Option<&Country> {
+fn get_by_flag(flag: &str) -> Option<&'static Country> {
     flag_country_index(flag).and_then(|index| COUNTRIES_BY_CODE_INDEX[index])
 }
 
-/// Resolves a flag emoji or country-like text to an ISO 3166-1 alpha-2 code.
+/// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1 alpha-2
+/// code.
+///
+/// This is the primary lookup entry point. ISO 3166-1 alpha-2, alpha-3, and zero-padded
+/// numeric codes are matched directly. Letter codes are only recognized in uppercase, so
+/// that short text such as `"can"` is read as a name. Other text input is matched
+/// case-insensitively and may resolve through aliases, normalized forms, or fuzzy matching.
 ///
-/// This is the primary lookup entry point. Text input is matched case-insensitively and
-/// may resolve through aliases, normalized forms, or fuzzy matching.
+/// England, Scotland, and Wales resolve to their ISO 3166-2 codes, such as `"GB-SCT"`, from
+/// their flag emojis, codes, or names, since they have flags of their own.
 ///
 /// # Arguments
-/// * `input` - A flag emoji or country-like text such as `"Canada"`, `"UK"`, or
-///   `"United States of America"`
+/// * `input` - A flag emoji, an ISO code such as `"DEU"` or `"276"`, or country-like text
+///   such as `"Canada"`, `"UK"`, or `"United States of America"`
 ///
 /// # Returns
-/// * `Some(&str)` - The resolved ISO 3166-1 alpha-2 code
+/// * `Some(&str)` - The resolved ISO 3166-1 alpha-2 code, or the ISO 3166-2 code of England,
+///   Scotland, or Wales
 /// * `None` - If the input is invalid, ambiguous, or not found
 ///
 /// # Examples
@@ -674,6 +1350,10 @@ fn get_by_flag(flag: &str) -> Option<&Country> {
 /// assert_eq!(code("🇨🇦"), Some("CA"));
 /// assert_eq!(code("🇺🇸"), Some("US"));
 ///
+/// // ISO codes to alpha-2 codes.
+/// assert_eq!(code("DEU"), Some("DE"));
+/// assert_eq!(code("076"), Some("BR"));
+///
 /// // Country names to codes.
 /// assert_eq!(code("Canada"), Some("CA"));
 /// assert_eq!(code("United States"), Some("US"));
@@ -686,22 +1366,29 @@ fn get_by_flag(flag: &str) -> Option<&Country> {
 /// assert_eq!(code("Republic of Korea"), Some("KR"));
 /// assert_eq!(code("United States of America"), Some("US"));
 ///
+/// // Subdivisions with their own flag.
+/// assert_eq!(code("🏴󠁧󠁢󠁳󠁣󠁴󠁿"), Some("GB-SCT"));
+/// assert_eq!(code("Wales"), Some("GB-WLS"));
+///
 /// // Invalid or ambiguous inputs.
 /// assert_eq!(code("ZZ"), None);
 /// assert_eq!(code("Korea"), None);
 /// ```
 pub fn code(input: &str) -> Option<&'static str> {
-    flag_to_code(input).or_else(|| name_to_code(input))
+    DEFAULT_MATCHER.code(input)
 }
 
 /// Resolves a country code or country-like text to a Unicode flag emoji.
 ///
-/// Country codes are handled directly. Other text inputs are first resolved through
-/// [`name_to_code`] and then converted to a flag emoji.
+/// ISO 3166-1 alpha-2, alpha-3, and zero-padded numeric codes are handled directly.
+/// Alpha-2 codes are matched case-insensitively, while alpha-3 codes are only recognized in
+/// uppercase, as in [`code`], so that `"can"` is read as a name. Other text inputs are first
+/// resolved through [`name_to_code`] and then converted to a flag emoji. England, Scotland,
+/// and Wales, by name or ISO 3166-2 code, resolve to their own flags.
 ///
 /// # Arguments
-/// * `input` - An ISO country code such as `"US"` or a country name such as
-///   `"United States"`
+/// * `input` - An ISO country code such as `"US"`, `"USA"`, or `"840"`, or a country name
+///   such as `"United States"`
 ///
 /// # Returns
 /// * `Some(String)` - The resolved Unicode flag emoji
@@ -715,35 +1402,44 @@ pub fn code(input: &str) -> Option<&'static str> {
 /// // Country codes to flags.
 /// assert_eq!(flag("US"), Some("🇺🇸".to_string()));
 /// assert_eq!(flag("CL"), Some("🇨🇱".to_string()));
+/// assert_eq!(flag("CHL"), Some("🇨🇱".to_string()));
+/// assert_eq!(flag("152"), Some("🇨🇱".to_string()));
 ///
 /// // Country names to flags.
 /// assert_eq!(flag("Chile"), Some("🇨🇱".to_string()));
 /// assert_eq!(flag("United Kingdom"), Some("🇬🇧".to_string()));
 /// assert_eq!(flag("UAE"), Some("🇦🇪".to_string()));
 ///
+/// // Subdivisions to flags.
+/// assert_eq!(flag("Scotland"), Some("🏴󠁧󠁢󠁳󠁣󠁴󠁿".to_string()));
+/// assert_eq!(flag("GB-ENG"), Some("🏴󠁧󠁢󠁥󠁮󠁧󠁿".to_string()));
+///
 /// // Invalid inputs.
 /// assert_eq!(flag("XX"), None);
 /// assert_eq!(flag("Atlantis"), None);
 /// ```
-pub fn flag(mut input: &str) -> Option<String> {
-    if let Some(flag) = code_to_flag(input) {
-        return Some(flag);
+pub fn flag(input: &str) -> Option<String> {
+    if let Some(country) = get_by_code_input(input).or_else(|| get_by_code(input)) {
+        return Some(country.flag());
     }
 
-    if let Some(code) = name_to_code(input) {
-        input = code;
+    match get_flag_subdivision(input) {
+        Some(subdivision) => Some(subdivision::subdivision_flag_emoji(subdivision)),
+        None => name_to_code(input).and_then(get_by_code).map(Country::flag),
     }
-
-    code_to_flag(input)
 }
 
-/// Resolves a flag emoji or ISO 3166-1 alpha-2 code to the preferred country name.
+/// Resolves a flag emoji or ISO 3166-1 alpha-2, alpha-3, or numeric code to the preferred
+/// country name.
 ///
 /// This function does not perform general name matching. If you need to resolve arbitrary
-/// country text first, use [`code`] or [`name_to_code`].
+/// country text first, use [`code`] or [`name_to_code`]. Alpha-2 codes are matched
+/// case-insensitively, while alpha-3 codes are only recognized in uppercase, as in [`flag`],
+/// so that `"can"` is not read as Canada.
 ///
 /// # Arguments
-/// * `input` - A flag emoji such as `"🇶🇦"` or an ISO country code such as `"QA"`
+/// * `input` - A flag emoji such as `"🇶🇦"` or `"🏴󠁧󠁢󠁷󠁬󠁳󠁿"`, or an ISO country code such as
+///   `"QA"`, `"QAT"`, or `"634"`
 ///
 /// # Returns
 /// * `Some(&str)` - The preferred country name
@@ -757,25 +1453,29 @@ pub fn flag(mut input: &str) -> Option<String> {
 /// // Flag emoji to name.
 /// assert_eq!(name("🇶🇦"), Some("Qatar"));
 /// assert_eq!(name("🇨🇦"), Some("Canada"));
+/// assert_eq!(name("🏴󠁧󠁢󠁷󠁬󠁳󠁿"), Some("Wales"));
 ///
 /// // Country code to name.
 /// assert_eq!(name("QA"), Some("Qatar"));
 /// assert_eq!(name("CA"), Some("Canada"));
 /// assert_eq!(name("GB"), Some("United Kingdom"));
 ///
+/// // Alpha-3 code to name.
+/// assert_eq!(name("USA"), Some("United States"));
+///
 /// // Invalid inputs.
 /// assert_eq!(name("XX"), None);
 /// assert_eq!(name("🏳️"), None);
 /// ```
-pub fn name(mut input: &str) -> Option<&'static str> {
-    if let Some(country_name) = code_to_name(input) {
-        return Some(country_name);
-    }
-
-    if let Some(code) = flag_to_code(input) {
-        input = code;
-    }
-    code_to_name(input)
+pub fn name(input: &str) -> Option<&'static str> {
+    get_by_code_input(input)
+        .or_else(|| get_by_code(input))
+        .or_else(|| get_by_flag(input))
+        .map(Country::name)
+        .or_else(|| {
+            subdivision::flag_to_subdivision_code(input)
+                .and_then(subdivision::flag_subdivision_name)
+        })
 }
 
 /// Returns whether an optional string is a valid ISO 3166-1 alpha-2 country code.
@@ -806,7 +1506,7 @@ pub fn is_code(code: Option<&str>) -> bool {
 /// This function only accepts country codes. Use [`name`] if the input may be a flag emoji.
 ///
 /// # Arguments
-/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive)
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
 ///
 /// # Returns
 /// * `Some(&str)` - The preferred country name
@@ -815,119 +1515,531 @@ pub fn is_code(code: Option<&str>) -> bool {
 /// # Examples
 ///
 /// ```
-/// use country_emoji::code_to_name;
+/// use country_emoji::{code_to_name, CountryCode};
 ///
 /// assert_eq!(code_to_name("US"), Some("United States"));
 /// assert_eq!(code_to_name("gb"), Some("United Kingdom"));
 /// assert_eq!(code_to_name("DE"), Some("Germany"));
 /// assert_eq!(code_to_name("ZZ"), None);
+///
+/// // Typed codes are checked at compile time.
+/// assert_eq!(code_to_name(CountryCode::DE), Some("Germany"));
 /// ```
-pub fn code_to_name(code: &str) -> Option<&'static str> {
-    get_by_code(code).map(|country| country.1[0])
+pub fn code_to_name(code: impl AsRef<str>) -> Option<&'static str> {
+    get_by_code(code.as_ref()).map(Country::name)
 }
 
-/// Converts an ISO 3166-1 alpha-2 country code to its flag emoji.
+/// Converts an ISO 3166-1 alpha-2 country code to the country name in the given language.
 ///
-/// This function only accepts country codes. Use [`flag`] if the input may be a country name.
+/// Names other than English require the matching `locale-*` cargo feature and follow the
+/// short display forms used by the Unicode CLDR, such as `"Südkorea"` rather than
+/// `"Korea, Republik"`.
 ///
 /// # Arguments
-/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive)
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+/// * `locale` - The language of the returned name
 ///
 /// # Returns
-/// * `Some(String)` - The corresponding Unicode flag emoji
+/// * `Some(&str)` - The localized country name
 /// * `None` - If the code is invalid or not found
 ///
 /// # Examples
 ///
 /// ```
-/// use country_emoji::code_to_flag;
+/// use country_emoji::{code_to_name_in, Locale};
 ///
-/// assert_eq!(code_to_flag("FR"), Some("🇫🇷".to_string()));
-/// assert_eq!(code_to_flag("jp"), Some("🇯🇵".to_string()));
-/// assert_eq!(code_to_flag("BR"), Some("🇧🇷".to_string()));
-/// assert_eq!(code_to_flag("ZZ"), None);
+/// assert_eq!(code_to_name_in("DE", Locale::En), Some("Germany"));
+/// assert_eq!(code_to_name_in("ZZ", Locale::En), None);
+///
+/// # #[cfg(all(feature = "locale-pt", feature = "locale-ja"))]
+/// # {
+/// assert_eq!(code_to_name_in("DE", Locale::Pt), Some("Alemanha"));
+/// assert_eq!(code_to_name_in("de", Locale::Ja), Some("ドイツ"));
+/// # }
 /// ```
-pub fn code_to_flag(code: &str) -> Option<String> {
-    get_by_code(code).map(|country| code_to_flag_emoji(country.0))
+pub fn code_to_name_in(code: impl AsRef<str>, locale: Locale) -> Option<&'static str> {
+    locale::localized_name(code.as_ref(), locale)
 }
 
-/// Returns whether a string is a valid country flag emoji.
+/// Returns the names a country uses for itself in its official languages.
+///
+/// The preferred native name comes first. Names in non-Latin scripts are followed by a
+/// common Latin transliteration, such as `"Nihon"` for `"日本"`. Every endonym is also
+/// recognized by [`code`] and [`name_to_code`].
 ///
 /// # Arguments
-/// * `flag` - A string that may contain a Unicode country flag emoji
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
 ///
 /// # Returns
-/// * `true` - If the input is a valid country flag emoji
-/// * `false` - If the input is not a valid country flag
+/// * `Some(&[&str])` - The native names
+/// * `None` - If the code is invalid or not found
 ///
 /// # Examples
 ///
 /// ```
-/// use country_emoji::is_country_flag;
+/// use country_emoji::{code, code_to_endonyms};
 ///
-/// assert!(is_country_flag("🇺🇸"));
-/// assert!(is_country_flag("🇨🇦"));
-/// assert!(is_country_flag("🇬🇧"));
-/// assert!(!is_country_flag("🏳️"));
-/// assert!(!is_country_flag("US"));
-/// assert!(!is_country_flag("🎌"));
+/// assert_eq!(code_to_endonyms("DE"), Some(&["Deutschland"][..]));
+/// assert_eq!(code_to_endonyms("fi"), Some(&["Suomi", "Finland"][..]));
+/// assert_eq!(code_to_endonyms("JP"), Some(&["日本", "Nihon", "Nippon"][..]));
+/// assert_eq!(code_to_endonyms("ZZ"), None);
+///
+/// assert_eq!(code("Suomi"), Some("FI"));
+/// assert_eq!(code("Nihon"), Some("JP"));
 /// ```
-pub fn is_country_flag(flag: &str) -> bool {
-    check_by_flag(flag)
+pub fn code_to_endonyms(code: impl AsRef<str>) -> Option<&'static [&'static str]> {
+    endonyms::endonyms(code.as_ref())
 }
 
-/// Converts a country flag emoji to its ISO 3166-1 alpha-2 code.
-///
-/// This function only accepts flag emojis. Use [`code`] if the input may be country text.
+/// Returns the preferred demonym of a country, the word for its people.
 ///
 /// # Arguments
-/// * `flag` - A Unicode country flag emoji
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
 ///
 /// # Returns
-/// * `Some(&str)` - The corresponding ISO 3166-1 alpha-2 country code
-/// * `None` - If the flag is invalid or not a country flag
+/// * `Some(&str)` - The demonym, such as `"Emirati"` for `"AE"`
+/// * `None` - If the code is invalid, not found, or has no demonym
 ///
 /// # Examples
 ///
 /// ```
-/// use country_emoji::flag_to_code;
+/// use country_emoji::code_to_demonym;
 ///
-/// assert_eq!(flag_to_code("🇺🇸"), Some("US"));
-/// assert_eq!(flag_to_code("🇨🇦"), Some("CA"));
-/// assert_eq!(flag_to_code("🇬🇧"), Some("GB"));
-/// assert_eq!(flag_to_code("🏳️"), None);
-/// assert_eq!(flag_to_code("US"), None);
+/// assert_eq!(code_to_demonym("AE"), Some("Emirati"));
+/// assert_eq!(code_to_demonym("gb"), Some("British"));
+/// assert_eq!(code_to_demonym("ZZ"), None);
 /// ```
-pub fn flag_to_code(flag: &str) -> Option<&'static str> {
-    get_by_flag(flag).map(|country| country.0)
+pub fn code_to_demonym(code: impl AsRef<str>) -> Option<&'static str> {
+    get_by_code(code.as_ref()).and_then(Country::demonym)
 }
 
-/// Resolves country-like text to an ISO 3166-1 alpha-2 code.
-///
-/// Text input is matched case-insensitively and may resolve through exact matches,
-/// normalized variants, aliases, or fuzzy matching.
+/// Converts a demonym to an ISO 3166-1 alpha-2 country code.
 ///
-/// # Supported Name Variations
-/// - Official names such as `"United States"` and `"United Kingdom"`
-/// - Common aliases such as `"USA"`, `"UK"`, and `"UAE"`
-/// - Formal names such as `"Republic of Korea"` and `"United States of America"`
-/// - Comma-reversed names such as `"Virgin Islands, British"`
-/// - Saint/St. variations such as `"Saint Lucia"`, `"St. Lucia"`, and `"St Lucia"`
-/// - Diacritic-insensitive matches such as `"Cote d'Ivoire"` and `"Côte d'Ivoire"`
-/// - `and`/`&` normalization such as `"Bosnia and Herzegovina"` and
-///   `"Bosnia & Herzegovina"`
+/// Only demonyms are recognized, compared after lowercasing or after the same normalization
+/// as country names. No fuzzy matching is attempted.
 ///
 /// # Arguments
-/// * `name` - Country-like text in one of the supported formats
+/// * `demonym` - A demonym such as `"Andorran"` or `"emirati"`
 ///
 /// # Returns
-/// * `Some(&str)` - The resolved ISO 3166-1 alpha-2 country code
-/// * `None` - If the name is invalid, too ambiguous, or not found
+/// * `Some(&str)` - The country code
+/// * `None` - If the text is not a known demonym
 ///
 /// # Examples
 ///
 /// ```
-/// use country_emoji::name_to_code;
+/// use country_emoji::demonym_to_code;
+///
+/// assert_eq!(demonym_to_code("Andorran"), Some("AD"));
+/// assert_eq!(demonym_to_code("  emirati "), Some("AE"));
+/// assert_eq!(demonym_to_code("Ivorian"), Some("CI"));
+/// assert_eq!(demonym_to_code("Germany"), None);
+/// ```
+pub fn demonym_to_code(demonym: &str) -> Option<&'static str> {
+    demonym_match(&COUNTRIES_INDEX, demonym.trim())
+}
+
+/// Suggests countries for partial input, for autocomplete and typeahead.
+///
+/// The input is normalized like country names and matched against the start of every name,
+/// alias, native name, and name in an enabled locale, and against the start of the words
+/// inside them. Preferred names rank before other English names, then matches inside those
+/// names, then native names and translations, then matches inside those. Within each group,
+/// names spelled with the same diacritics as the input come first, then official codes, then
+/// shorter names.
+///
+/// # Arguments
+/// * `prefix` - The text typed so far, such as `"uni"`, `"cô"`, or `"st vin"`
+/// * `limit` - The maximum number of suggestions
+///
+/// # Returns
+/// Up to `limit` distinct ISO 3166-1 alpha-2 codes, most relevant first, or none when the
+/// prefix has no letters
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::suggest;
+///
+/// assert_eq!(suggest("uni", 3), ["US", "GB", "AE"]);
+/// assert_eq!(suggest("cô", 1), ["CI"]);
+/// assert_eq!(suggest("st vin", 5), ["VC"]);
+/// assert_eq!(suggest("kingdom", 1), ["GB"]);
+/// assert!(suggest("xyz", 5).is_empty());
+/// assert!(suggest("-", 5).is_empty());
+/// ```
+pub fn suggest(prefix: &str, limit: usize) -> Vec<&'static str> {
+    suggest::suggest(prefix, limit)
+}
+
+/// Converts an ISO 3166-1 alpha-2 country code to its flag emoji.
+///
+/// This function only accepts country codes. Use [`flag`] if the input may be a country name.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+///
+/// # Returns
+/// * `Some(String)` - The corresponding Unicode flag emoji
+/// * `None` - If the code is invalid or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{code_to_flag, CountryCode};
+///
+/// assert_eq!(code_to_flag("FR"), Some("🇫🇷".to_string()));
+/// assert_eq!(code_to_flag("jp"), Some("🇯🇵".to_string()));
+/// assert_eq!(code_to_flag("BR"), Some("🇧🇷".to_string()));
+/// assert_eq!(code_to_flag("ZZ"), None);
+///
+/// // Typed codes are checked at compile time.
+/// assert_eq!(code_to_flag(CountryCode::BR), Some("🇧🇷".to_string()));
+/// ```
+pub fn code_to_flag(code: impl AsRef<str>) -> Option<String> {
+    get_by_code(code.as_ref()).map(Country::flag)
+}
+
+/// Converts an ISO 3166-1 alpha-2 country code to its alpha-3 code.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+///
+/// # Returns
+/// * `Some(&str)` - The corresponding ISO 3166-1 alpha-3 code
+/// * `None` - If the code is invalid, not found, or has no alpha-3 code
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::alpha2_to_alpha3;
+///
+/// assert_eq!(alpha2_to_alpha3("DE"), Some("DEU"));
+/// assert_eq!(alpha2_to_alpha3("us"), Some("USA"));
+/// assert_eq!(alpha2_to_alpha3("ZZ"), None);
+/// ```
+pub fn alpha2_to_alpha3(code: impl AsRef<str>) -> Option<&'static str> {
+    get_by_code(code.as_ref()).and_then(Country::alpha3)
+}
+
+/// Converts an ISO 3166-1 alpha-3 country code to its alpha-2 code.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-3 country code (case-insensitive)
+///
+/// # Returns
+/// * `Some(&str)` - The corresponding ISO 3166-1 alpha-2 code
+/// * `None` - If the code is invalid or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::alpha3_to_alpha2;
+///
+/// assert_eq!(alpha3_to_alpha2("DEU"), Some("DE"));
+/// assert_eq!(alpha3_to_alpha2("bra"), Some("BR"));
+/// assert_eq!(alpha3_to_alpha2("ZZZ"), None);
+/// ```
+pub fn alpha3_to_alpha2(code: &str) -> Option<&'static str> {
+    get_by_alpha3(code).map(Country::code)
+}
+
+/// Converts an ISO 3166-1 alpha-2 country code to its numeric code.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+///
+/// # Returns
+/// * `Some(&str)` - The corresponding zero-padded ISO 3166-1 numeric code
+/// * `None` - If the code is invalid, not found, or has no numeric code
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::code_to_numeric;
+///
+/// assert_eq!(code_to_numeric("BR"), Some("076"));
+/// assert_eq!(code_to_numeric("us"), Some("840"));
+/// assert_eq!(code_to_numeric("EU"), None);
+/// ```
+pub fn code_to_numeric(code: impl AsRef<str>) -> Option<&'static str> {
+    get_by_code(code.as_ref()).and_then(Country::numeric)
+}
+
+/// Converts a zero-padded ISO 3166-1 numeric code to its alpha-2 code.
+///
+/// # Arguments
+/// * `numeric` - A three-digit ISO 3166-1 numeric code such as `"076"`
+///
+/// # Returns
+/// * `Some(&str)` - The corresponding ISO 3166-1 alpha-2 code
+/// * `None` - If the code is not three digits or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::numeric_to_code;
+///
+/// assert_eq!(numeric_to_code("076"), Some("BR"));
+/// assert_eq!(numeric_to_code("840"), Some("US"));
+/// assert_eq!(numeric_to_code("76"), None);
+/// assert_eq!(numeric_to_code("999"), None);
+/// ```
+pub fn numeric_to_code(numeric: &str) -> Option<&'static str> {
+    get_by_numeric(numeric).map(Country::code)
+}
+
+/// Returns the ISO 3166-1 assignment status of a country code.
+///
+/// Use this to reject codes that are not officially assigned, such as `EU` or `XK`, while
+/// still accepting them for display purposes elsewhere.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+///
+/// # Returns
+/// * `Some(CodeStatus)` - The assignment status of the code
+/// * `None` - If the code is invalid or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{code_status, CodeStatus};
+///
+/// assert_eq!(code_status("BR"), Some(CodeStatus::Official));
+/// assert_eq!(code_status("un"), Some(CodeStatus::ExceptionallyReserved));
+/// assert_eq!(code_status("AN"), Some(CodeStatus::TransitionallyReserved));
+/// assert_eq!(code_status("XK"), Some(CodeStatus::UserAssigned));
+/// assert_eq!(code_status("ZZ"), None);
+/// ```
+pub fn code_status(code: impl AsRef<str>) -> Option<CodeStatus> {
+    get_by_code(code.as_ref()).map(Country::status)
+}
+
+/// Returns whether a string is a valid country flag emoji.
+///
+/// # Arguments
+/// * `flag` - A string that may contain a Unicode country flag emoji
+///
+/// # Returns
+/// * `true` - If the input is a valid country flag emoji
+/// * `false` - If the input is not a valid country flag
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::is_country_flag;
+///
+/// assert!(is_country_flag("🇺🇸"));
+/// assert!(is_country_flag("🇨🇦"));
+/// assert!(is_country_flag("🇬🇧"));
+/// assert!(!is_country_flag("🏳️"));
+/// assert!(!is_country_flag("US"));
+/// assert!(!is_country_flag("🎌"));
+/// ```
+pub fn is_country_flag(flag: &str) -> bool {
+    check_by_flag(flag)
+}
+
+/// Converts a country flag emoji to its ISO 3166-1 alpha-2 code.
+///
+/// This function only accepts flag emojis. Use [`code`] if the input may be country text.
+///
+/// # Arguments
+/// * `flag` - A Unicode country flag emoji
+///
+/// # Returns
+/// * `Some(&str)` - The corresponding ISO 3166-1 alpha-2 country code
+/// * `None` - If the flag is invalid or not a country flag
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::flag_to_code;
+///
+/// assert_eq!(flag_to_code("🇺🇸"), Some("US"));
+/// assert_eq!(flag_to_code("🇨🇦"), Some("CA"));
+/// assert_eq!(flag_to_code("🇬🇧"), Some("GB"));
+/// assert_eq!(flag_to_code("🏳️"), None);
+/// assert_eq!(flag_to_code("US"), None);
+/// ```
+pub fn flag_to_code(flag: &str) -> Option<&'static str> {
+    get_by_flag(flag).map(Country::code)
+}
+
+/// Converts a subdivision flag emoji such as `"🏴󠁧󠁢󠁳󠁣󠁴󠁿"` to its ISO 3166-2 code.
+///
+/// Subdivision flags are emoji tag sequences. Only the flags recommended for general
+/// interchange are recognized: England, Scotland, and Wales.
+///
+/// # Arguments
+/// * `flag` - A subdivision flag emoji
+///
+/// # Returns
+/// * `Some(&str)` - The ISO 3166-2 code, such as `"GB-SCT"`
+/// * `None` - If the input is not a recognized subdivision flag
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::flag_to_subdivision;
+///
+/// assert_eq!(flag_to_subdivision("🏴󠁧󠁢󠁥󠁮󠁧󠁿"), Some("GB-ENG"));
+/// assert_eq!(flag_to_subdivision("🏴󠁧󠁢󠁳󠁣󠁴󠁿"), Some("GB-SCT"));
+/// assert_eq!(flag_to_subdivision("🏴"), None);
+/// assert_eq!(flag_to_subdivision("🇬🇧"), None);
+/// ```
+pub fn flag_to_subdivision(flag: &str) -> Option<&'static str> {
+    subdivision::flag_to_subdivision_code(flag)
+}
+
+/// Converts an ISO 3166-2 subdivision code such as `"GB-ENG"` to its flag emoji.
+///
+/// Only the subdivisions with a flag recommended for general interchange are supported:
+/// England, Scotland, and Wales.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-2 subdivision code (case-insensitive)
+///
+/// # Returns
+/// * `Some(String)` - The flag emoji tag sequence
+/// * `None` - If the subdivision has no flag emoji
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::subdivision_to_flag;
+///
+/// assert_eq!(subdivision_to_flag("GB-ENG"), Some("🏴󠁧󠁢󠁥󠁮󠁧󠁿".to_string()));
+/// assert_eq!(subdivision_to_flag("gb-wls"), Some("🏴󠁧󠁢󠁷󠁬󠁳󠁿".to_string()));
+/// assert_eq!(subdivision_to_flag("GB-NIR"), None);
+/// assert_eq!(subdivision_to_flag("GB"), None);
+/// ```
+pub fn subdivision_to_flag(code: &str) -> Option<String> {
+    subdivision::flag_subdivision_by_code(code).map(subdivision::subdivision_flag_emoji)
+}
+
+/// Returns the ISO 3166-2 name of a subdivision.
+///
+/// England, Scotland, and Wales are always known. The subdivisions of Brazil, Canada,
+/// Germany, and the United States require the `subdivisions` feature.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-2 subdivision code such as `"US-CA"` (case-insensitive)
+///
+/// # Returns
+/// * `Some(&str)` - The subdivision name
+/// * `None` - If the subdivision is unknown
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::subdivision_name;
+///
+/// assert_eq!(subdivision_name("GB-WLS"), Some("Wales"));
+/// # #[cfg(feature = "subdivisions")]
+/// # {
+/// assert_eq!(subdivision_name("US-CA"), Some("California"));
+/// assert_eq!(subdivision_name("br-sp"), Some("São Paulo"));
+/// # }
+/// assert_eq!(subdivision_name("US-ZZ"), None);
+/// ```
+pub fn subdivision_name(code: &str) -> Option<&'static str> {
+    subdivision::subdivision_by_code(code).map(|(_, names)| names[0])
+}
+
+/// Returns the ISO 3166-1 alpha-2 code of the country a subdivision belongs to.
+///
+/// # Arguments
+/// * `code` - An ISO 3166-2 subdivision code such as `"BR-SP"` (case-insensitive)
+///
+/// # Returns
+/// * `Some(&str)` - The country code
+/// * `None` - If the subdivision is unknown
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::subdivision_to_country;
+///
+/// assert_eq!(subdivision_to_country("GB-SCT"), Some("GB"));
+/// # #[cfg(feature = "subdivisions")]
+/// assert_eq!(subdivision_to_country("BR-SP"), Some("BR"));
+/// assert_eq!(subdivision_to_country("BR"), None);
+/// ```
+pub fn subdivision_to_country(code: &str) -> Option<&'static str> {
+    let (code, _) = subdivision::subdivision_by_code(code)?;
+    code.split_once('-')
+        .and_then(|(country, _)| get_by_code(country))
+        .map(Country::code)
+}
+
+/// Resolves a subdivision name to its ISO 3166-2 code.
+///
+/// Names are matched like [`name_to_code`] matches country names, including normalization
+/// and fuzzy matching, but only against subdivisions. Country-level lookups such as [`code`]
+/// never return these subdivisions, so `"Georgia"` stays a country there. A fuzzy match is
+/// rejected when another subdivision matches about as well, as with `"Carolina"`.
+///
+/// # Arguments
+/// * `name` - A subdivision name such as `"São Paulo"` or `"Bavaria"`
+///
+/// # Returns
+/// * `Some(&str)` - The ISO 3166-2 code
+/// * `None` - If the name is not a known subdivision, or is ambiguous
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::subdivision_name_to_code;
+///
+/// assert_eq!(subdivision_name_to_code("Scotland"), Some("GB-SCT"));
+/// # #[cfg(feature = "subdivisions")]
+/// # {
+/// assert_eq!(subdivision_name_to_code("São Paulo"), Some("BR-SP"));
+/// assert_eq!(subdivision_name_to_code("sao paulo"), Some("BR-SP"));
+/// assert_eq!(subdivision_name_to_code("Bavaria"), Some("DE-BY"));
+/// assert_eq!(subdivision_name_to_code("Pensylvania"), Some("US-PA"));
+/// assert_eq!(subdivision_name_to_code("Carolina"), None);
+/// # }
+/// ```
+pub fn subdivision_name_to_code(name: &str) -> Option<&'static str> {
+    DEFAULT_MATCHER.subdivision_name_to_code(name)
+}
+
+/// Resolves country-like text to an ISO 3166-1 alpha-2 code.
+///
+/// Text input is matched case-insensitively and may resolve through exact matches,
+/// normalized variants, aliases, or fuzzy matching. Unlike [`code`], this never resolves the
+/// names of England, Scotland, and Wales, which have no ISO 3166-1 code of their own.
+///
+/// # Supported Name Variations
+/// - Official names such as `"United States"` and `"United Kingdom"`
+/// - Common aliases such as `"USA"`, `"UK"`, and `"UAE"`
+/// - Formal names such as `"Republic of Korea"` and `"United States of America"`
+/// - Comma-reversed names such as `"Virgin Islands, British"`
+/// - Saint/St. variations such as `"Saint Lucia"`, `"St. Lucia"`, and `"St Lucia"`
+/// - Diacritic-insensitive matches such as `"Cote d'Ivoire"` and `"Côte d'Ivoire"`
+/// - `and`/`&` normalization such as `"Bosnia and Herzegovina"` and
+///   `"Bosnia & Herzegovina"`
+/// - Common misspellings such as `"Germny"` and `"Phillipines"`
+/// - Native names such as `"Deutschland"`, `"Suomi"`, or `"日本"`
+/// - Demonyms such as `"British"` or `"Emirati"`, unless disabled with
+///   [`MatcherBuilder::demonyms`]
+/// - Names in every language enabled through a `locale-*` feature, such as `"Alemanha"` or
+///   `"Allemagne"`
+///
+/// # Arguments
+/// * `name` - Country-like text in one of the supported formats
+///
+/// # Returns
+/// * `Some(&str)` - The resolved ISO 3166-1 alpha-2 country code
+/// * `None` - If the name is invalid, too ambiguous, or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::name_to_code;
 ///
 /// // Official names.
 /// assert_eq!(name_to_code("Canada"), Some("CA"));
@@ -950,32 +2062,464 @@ pub fn flag_to_code(flag: &str) -> Option<&'static str> {
 /// assert_eq!(name_to_code("Cote d'Ivoire"), Some("CI"));
 /// assert_eq!(name_to_code("Côte d'Ivoire"), Some("CI"));
 ///
+/// // Misspellings.
+/// assert_eq!(name_to_code("Germny"), Some("DE"));
+/// assert_eq!(name_to_code("Argentia"), Some("AR"));
+///
+/// // Native names.
+/// assert_eq!(name_to_code("Deutschland"), Some("DE"));
+/// assert_eq!(name_to_code("Россия"), Some("RU"));
+///
+/// // Localized names, with the `locale-pt` feature.
+/// # #[cfg(feature = "locale-pt")]
+/// assert_eq!(name_to_code("Alemanha"), Some("DE"));
+///
 /// // Invalid or ambiguous input.
 /// assert_eq!(name_to_code("Atlantis"), None);
 /// assert_eq!(name_to_code("Korea"), None);
 /// assert_eq!(name_to_code("United"), None);
 /// ```
 pub fn name_to_code(name: &str) -> Option<&'static str> {
-    let trimmed_input = name.trim();
-    if trimmed_input.is_empty() {
-        return None;
+    DEFAULT_MATCHER.name_to_code(name)
+}
+
+/// The matching stage that resolved a country name.
+///
+/// Variants are listed from most to least reliable.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// The input matched a known name, alias, or stored normalized form after lowercasing.
+    Exact,
+    /// The input matched a known name after whitespace, diacritic, `St.`, and `&`
+    /// normalization.
+    Normalized,
+    /// The input matched after reversing a comma-separated name or removing government
+    /// titles such as `"Republic of"`.
+    GovernmentPattern,
+    /// The input matched a demonym such as `"German"`.
+    Demonym,
+    /// The input was resolved by fuzzy similarity scoring.
+    Fuzzy,
+}
+
+/// The result of resolving country-like text, with its confidence score and matching stage.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{name_match, MatchKind};
+///
+/// let germany = name_match("Germany").unwrap();
+/// assert_eq!(germany.code(), "DE");
+/// assert_eq!(germany.kind(), MatchKind::Exact);
+/// assert_eq!(germany.score(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Match {
+    code: &'static str,
+    score: f32,
+    kind: MatchKind,
+}
+
+impl Match {
+    fn new(code: &'static str, score: f32, kind: MatchKind) -> Self {
+        Self { code, score, kind }
     }
 
-    if let Some(code) = direct_name_match(trimmed_input) {
-        return Some(code);
+    /// Returns the resolved ISO 3166-1 alpha-2 code.
+    pub fn code(&self) -> &'static str {
+        self.code
     }
 
-    let normalized_input = normalize_text(trimmed_input);
-    if let Some(code) = normalized_name_match(&normalized_input) {
-        return Some(code);
+    /// Returns the confidence score in `0.0..=1.0`.
+    ///
+    /// Exact, normalized, government-pattern, and demonym matches always score `1.0`. Fuzzy
+    /// matches report their similarity score.
+    pub fn score(&self) -> f32 {
+        self.score
     }
 
-    let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
-    if should_reject_fuzzy_match(&input_words) {
-        return None;
+    /// Returns the matching stage that resolved the input.
+    pub fn kind(&self) -> MatchKind {
+        self.kind
+    }
+
+    /// Returns the resolved country entry.
+    pub fn country(&self) -> &'static Country {
+        get_by_code(self.code).expect("matches always resolve to dataset entries")
+    }
+}
+
+/// Resolves country-like text and reports how confident the match is.
+///
+/// This runs the same matching stages as [`name_to_code`] and returns the first successful
+/// one, so `name_match(input).map(|m| m.code())` always equals `name_to_code(input)`.
+///
+/// # Arguments
+/// * `name` - Country-like text in one of the supported formats
+///
+/// # Returns
+/// * `Some(Match)` - The resolved code, its score, and the [`MatchKind`] that produced it
+/// * `None` - If the name is invalid, too ambiguous, or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{name_match, MatchKind};
+///
+/// assert_eq!(name_match("UK").unwrap().kind(), MatchKind::Exact);
+/// assert_eq!(name_match("St Lucia").unwrap().kind(), MatchKind::Normalized);
+///
+/// let france = name_match("Republic of France").unwrap();
+/// assert_eq!(france.code(), "FR");
+/// assert_eq!(france.kind(), MatchKind::GovernmentPattern);
+///
+/// let vatican = name_match("Holy See (Vatican City State)").unwrap();
+/// assert_eq!(vatican.code(), "VA");
+/// assert_eq!(vatican.kind(), MatchKind::Fuzzy);
+/// assert!(vatican.score() < 1.0);
+///
+/// assert_eq!(name_match("Atlantis"), None);
+/// ```
+pub fn name_match(name: &str) -> Option<Match> {
+    DEFAULT_MATCHER.name_match(name)
+}
+
+/// Returns the best-scoring country codes for country-like text, highest score first.
+///
+/// Unlike [`name_to_code`], this does not apply a score threshold or reject ambiguous
+/// input, so it can be used to offer suggestions such as "Did you mean Niger or Nigeria?".
+/// Every country is scored, not only those sharing a word with the input. Exact and
+/// normalized name matches score `1.0`. Candidates with a score of zero are omitted.
+///
+/// # Arguments
+/// * `input` - Country-like text
+/// * `limit` - The maximum number of candidates to return
+///
+/// # Returns
+/// A list of `(code, score)` pairs sorted by descending score, with scores in `0.0..=1.0`.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::name_candidates;
+///
+/// let candidates = name_candidates("Niger", 2);
+/// assert_eq!(candidates[0], ("NE", 1.0));
+/// assert_eq!(candidates[1].0, "NG");
+///
+/// // Ambiguous input still yields its candidates.
+/// let codes: Vec<&str> = name_candidates("Korea", 2)
+///     .into_iter()
+///     .map(|(code, _)| code)
+///     .collect();
+/// assert!(codes.contains(&"KP") && codes.contains(&"KR"));
+///
+/// assert!(name_candidates("Atlantis", 5).is_empty());
+/// ```
+pub fn name_candidates(input: &str, limit: usize) -> Vec<(&'static str, f32)> {
+    DEFAULT_MATCHER.name_candidates(input, limit)
+}
+
+/// The reason a lookup through [`try_code`] or [`try_name_to_code`] failed.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{try_code, LookupError};
+///
+/// assert_eq!(try_code("Atlantis"), Err(LookupError::NotFound));
+/// assert_eq!(try_code("United"), Err(LookupError::TooGeneric));
+/// assert_eq!(
+///     try_code("Korea"),
+///     Err(LookupError::Ambiguous {
+///         candidates: vec!["KP", "KR"]
+///     })
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    /// The input does not match any country.
+    NotFound,
+    /// The input matches several countries equally well, such as `"Korea"`.
+    Ambiguous {
+        /// The ISO 3166-1 alpha-2 codes of the plausible matches, in alphabetical order.
+        candidates: Vec<&'static str>,
+    },
+    /// The input only contains generic words such as `"United"` or `"Republic"`.
+    TooGeneric,
+}
+
+impl std::fmt::Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::NotFound => f.write_str("no matching country found"),
+            LookupError::Ambiguous { candidates } => {
+                write!(
+                    f,
+                    "ambiguous country name, candidates: {}",
+                    candidates.join(", ")
+                )
+            }
+            LookupError::TooGeneric => f.write_str("country name is too generic"),
+        }
     }
+}
+
+impl std::error::Error for LookupError {}
 
-    best_fuzzy_match(&normalized_input, &input_words)
-        .filter(|(_, score)| *score >= fuzzy_match_threshold(&input_words))
-        .map(|(code, _)| code)
+/// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1 alpha-2
+/// code, explaining why the lookup failed.
+///
+/// This accepts the same inputs as [`code`] and succeeds exactly when it does. Input shared by
+/// several countries, such as `"Congo"`, makes [`code`] return `None` and is reported here as
+/// ambiguous.
+///
+/// # Arguments
+/// * `input` - A flag emoji, an ISO code, or country-like text
+///
+/// # Returns
+/// * `Ok(&str)` - The resolved ISO 3166-1 alpha-2 code, or the ISO 3166-2 code of England,
+///   Scotland, or Wales
+/// * `Err(LookupError)` - Whether the input was not found, ambiguous, or too generic
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{try_code, LookupError};
+///
+/// assert_eq!(try_code("🇨🇦"), Ok("CA"));
+/// assert_eq!(try_code("Canada"), Ok("CA"));
+/// assert_eq!(try_code("Wales"), Ok("GB-WLS"));
+///
+/// match try_code("Korea") {
+///     Err(LookupError::Ambiguous { candidates }) => assert_eq!(candidates, ["KP", "KR"]),
+///     other => panic!("unexpected result: {other:?}"),
+/// }
+/// ```
+pub fn try_code(input: &str) -> Result<&'static str, LookupError> {
+    DEFAULT_MATCHER.try_code(input)
+}
+
+/// Resolves country-like text to an ISO 3166-1 alpha-2 code, explaining why the lookup
+/// failed.
+///
+/// This accepts the same inputs as [`name_to_code`] and succeeds exactly when it does. Input
+/// shared by several countries, such as `"Congo"`, makes [`name_to_code`] return `None` and is
+/// reported here as ambiguous.
+/// When it fails, the error distinguishes unknown names from ambiguous or generic ones so
+/// callers can ask the user to disambiguate.
+///
+/// # Arguments
+/// * `name` - Country-like text in one of the supported formats
+///
+/// # Returns
+/// * `Ok(&str)` - The resolved ISO 3166-1 alpha-2 code
+/// * `Err(LookupError)` - Whether the name was not found, ambiguous, or too generic
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{try_name_to_code, LookupError};
+///
+/// assert_eq!(try_name_to_code("South Korea"), Ok("KR"));
+/// assert_eq!(try_name_to_code("Atlantis"), Err(LookupError::NotFound));
+/// assert_eq!(try_name_to_code("Republic"), Err(LookupError::TooGeneric));
+/// assert!(matches!(
+///     try_name_to_code("Korea"),
+///     Err(LookupError::Ambiguous { .. })
+/// ));
+/// ```
+pub fn try_name_to_code(name: &str) -> Result<&'static str, LookupError> {
+    DEFAULT_MATCHER.try_name_to_code(name)
+}
+
+/// Resolves a flag emoji, ISO country code, or country-like text to an officially assigned
+/// ISO 3166-1 alpha-2 code.
+///
+/// This behaves like [`code`], but never returns codes whose [`CodeStatus`] is not
+/// [`CodeStatus::Official`], such as `EU`, `UN`, `AN`, or `XK`, or the ISO 3166-2 codes that
+/// [`code`] returns for England, Scotland, and Wales. Use it in validation paths that must
+/// only accept ISO 3166-1 countries.
+///
+/// # Arguments
+/// * `input` - A flag emoji, an ISO code, or country-like text
+///
+/// # Returns
+/// * `Some(&str)` - The resolved officially assigned ISO 3166-1 alpha-2 code
+/// * `None` - If the input is invalid, ambiguous, not found, or not officially assigned
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{code, code_strict};
+///
+/// assert_eq!(code_strict("Germany"), Some("DE"));
+/// assert_eq!(code_strict("🇧🇷"), Some("BR"));
+///
+/// // Non-official entries are rejected.
+/// assert_eq!(code("European Union"), Some("EU"));
+/// assert_eq!(code_strict("European Union"), None);
+/// assert_eq!(code_strict("🇪🇺"), None);
+/// assert_eq!(code_strict("XK"), None);
+/// assert_eq!(code_strict("Scotland"), None);
+/// ```
+pub fn code_strict(input: &str) -> Option<&'static str> {
+    flag_to_code(input)
+        .or_else(|| get_by_code_input(input).map(Country::code))
+        .filter(|code| is_official_code(code))
+        .or_else(|| name_to_code_strict(input))
+}
+
+/// Resolves country-like text to an officially assigned ISO 3166-1 alpha-2 code.
+///
+/// This behaves like [`name_to_code`], but returns `None` when the name resolves to an entry
+/// whose [`CodeStatus`] is not [`CodeStatus::Official`].
+///
+/// # Arguments
+/// * `name` - Country-like text in one of the supported formats
+///
+/// # Returns
+/// * `Some(&str)` - The resolved officially assigned ISO 3166-1 alpha-2 code
+/// * `None` - If the name is invalid, too ambiguous, not found, or not officially assigned
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::name_to_code_strict;
+///
+/// assert_eq!(name_to_code_strict("United Kingdom"), Some("GB"));
+/// assert_eq!(name_to_code_strict("Kosovo"), None);
+/// assert_eq!(name_to_code_strict("Netherlands Antilles"), None);
+/// assert_eq!(name_to_code_strict("United Nations"), None);
+/// ```
+pub fn name_to_code_strict(name: &str) -> Option<&'static str> {
+    name_to_code(name).filter(|code| is_official_code(code))
+}
+
+/// Returns whether an optional string is an officially assigned ISO 3166-1 alpha-2 code.
+///
+/// This behaves like [`is_code`], but returns `false` for codes whose [`CodeStatus`] is not
+/// [`CodeStatus::Official`].
+///
+/// # Arguments
+/// * `code` - An optional string slice that may contain a country code
+///
+/// # Returns
+/// * `true` - If `code` is `Some` and is an officially assigned country code
+/// * `false` - If the code is None, invalid, or not officially assigned
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{is_code, is_code_strict};
+///
+/// assert!(is_code_strict(Some("US")));
+/// assert!(is_code(Some("EU")));
+/// assert!(!is_code_strict(Some("EU")));
+/// assert!(!is_code_strict(Some("XK")));
+/// assert!(!is_code_strict(None));
+/// ```
+pub fn is_code_strict(code: Option<&str>) -> bool {
+    code.is_some_and(is_official_code)
+}
+
+/// Resolves a flag emoji or country-like text to a [`Country`].
+///
+/// This accepts the same inputs as [`code`] and returns the full country entry instead of
+/// only its code. England, Scotland, and Wales resolve to the country they are part of.
+///
+/// # Arguments
+/// * `input` - A flag emoji or country-like text such as `"Canada"` or `"UK"`
+///
+/// # Returns
+/// * `Some(&Country)` - The resolved country entry
+/// * `None` - If the input is invalid, ambiguous, or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::country;
+///
+/// let canada = country("🇨🇦").unwrap();
+/// assert_eq!(canada.code(), "CA");
+/// assert_eq!(canada.name(), "Canada");
+///
+/// let uk = country("UK").unwrap();
+/// assert_eq!(uk.code(), "GB");
+/// assert_eq!(uk.aliases(), ["UK"]);
+/// assert_eq!(uk.demonym(), Some("British"));
+///
+/// assert_eq!(country("Scotland").map(|country| country.code()), Some("GB"));
+/// assert_eq!(country("Atlantis"), None);
+/// ```
+pub fn country(input: &str) -> Option<&'static Country> {
+    code(input).and_then(get_by_resolved_code)
+}
+
+/// Converts an ISO 3166-1 alpha-2 country code to a [`Country`].
+///
+/// # Arguments
+/// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+///
+/// # Returns
+/// * `Some(&Country)` - The corresponding country entry
+/// * `None` - If the code is invalid or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::code_to_country;
+///
+/// assert_eq!(code_to_country("br").map(|country| country.name()), Some("Brazil"));
+/// assert_eq!(code_to_country("ZZ"), None);
+/// ```
+pub fn code_to_country(code: impl AsRef<str>) -> Option<&'static Country> {
+    get_by_code(code.as_ref())
+}
+
+/// Converts a country flag emoji to a [`Country`].
+///
+/// # Arguments
+/// * `flag` - A Unicode country flag emoji
+///
+/// # Returns
+/// * `Some(&Country)` - The corresponding country entry
+/// * `None` - If the flag is invalid or not a country flag
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::flag_to_country;
+///
+/// assert_eq!(flag_to_country("🇯🇵").map(|country| country.code()), Some("JP"));
+/// assert_eq!(flag_to_country("🏳️"), None);
+/// ```
+pub fn flag_to_country(flag: &str) -> Option<&'static Country> {
+    get_by_flag(flag)
+}
+
+/// Resolves country-like text to a [`Country`].
+///
+/// Matching follows the same rules as [`name_to_code`].
+///
+/// # Arguments
+/// * `name` - Country-like text in one of the supported formats
+///
+/// # Returns
+/// * `Some(&Country)` - The resolved country entry
+/// * `None` - If the name is invalid, too ambiguous, or not found
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::name_to_country;
+///
+/// assert_eq!(name_to_country("Ivory Coast").map(|country| country.code()), Some("CI"));
+/// assert_eq!(name_to_country("Korea"), None);
+/// ```
+pub fn name_to_country(name: &str) -> Option<&'static Country> {
+    name_to_code(name).and_then(get_by_code)
 }
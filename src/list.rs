@@ -0,0 +1,321 @@
+use crate::countries::COUNTRIES;
+#[cfg(feature = "locale-ja")]
+use crate::locale::japanese_reading;
+use crate::{normalize_text, CodeStatus, Country, Locale};
+
+/// The order of the entries listed by a [`CountryList`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CountryOrder {
+    /// Alphabetical by ISO 3166-1 alpha-2 code.
+    Code,
+    /// Alphabetical by display name in the given language, following the collation rules
+    /// described in [`sorted_by_name`].
+    Name(Locale),
+}
+
+/// Lists dataset entries filtered by [`CodeStatus`], for building dropdowns and pickers.
+///
+/// A new list contains the officially assigned codes ordered by code, which covers every
+/// country and territory with its own ISO 3166-1 code. Other statuses, such as the
+/// exceptionally reserved `AC` for Ascension Island or the legacy `AN` for the Netherlands
+/// Antilles, can be included as well. Territories, as reported by [`Country::is_territory`],
+/// can be left out to only list sovereign states.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{CodeStatus, CountryList, CountryOrder, Locale};
+///
+/// let official: Vec<_> = CountryList::new().iter().map(|country| country.code()).collect();
+/// assert_eq!(official.len(), 249);
+/// assert_eq!(&official[..3], ["AD", "AE", "AF"]);
+/// assert!(!official.contains(&"AN"));
+///
+/// let with_legacy = CountryList::new().include(CodeStatus::TransitionallyReserved);
+/// assert!(with_legacy.iter().any(|country| country.code() == "AN"));
+///
+/// let by_name = CountryList::new().order(CountryOrder::Name(Locale::En));
+/// let names: Vec<_> = by_name.iter().map(|country| country.name()).take(3).collect();
+/// assert_eq!(names, ["Afghanistan", "Åland Islands", "Albania"]);
+///
+/// let states = CountryList::new().territories(false);
+/// assert!(states.iter().all(|country| !country.is_territory()));
+/// assert!(!states.iter().any(|country| country.code() == "PR"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CountryList {
+    statuses: Vec<CodeStatus>,
+    territories: bool,
+    order: CountryOrder,
+}
+
+impl CountryList {
+    /// Returns a list of the officially assigned codes, ordered by code.
+    pub fn new() -> Self {
+        Self {
+            statuses: vec![CodeStatus::Official],
+            territories: true,
+            order: CountryOrder::Code,
+        }
+    }
+
+    /// Returns a list of every dataset entry, whatever its status, ordered by code.
+    pub fn all() -> Self {
+        Self::new()
+            .include(CodeStatus::ExceptionallyReserved)
+            .include(CodeStatus::TransitionallyReserved)
+            .include(CodeStatus::UserAssigned)
+    }
+
+    /// Also lists the entries with the given status.
+    pub fn include(mut self, status: CodeStatus) -> Self {
+        if !self.statuses.contains(&status) {
+            self.statuses.push(status);
+        }
+        self
+    }
+
+    /// Stops listing the entries with the given status.
+    pub fn exclude(mut self, status: CodeStatus) -> Self {
+        self.statuses.retain(|&included| included != status);
+        self
+    }
+
+    /// Sets whether territories are listed along with sovereign states, which they are by
+    /// default.
+    pub fn territories(mut self, territories: bool) -> Self {
+        self.territories = territories;
+        self
+    }
+
+    /// Sets the order of the listed entries.
+    pub fn order(mut self, order: CountryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns the listed entries in order.
+    pub fn iter(&self) -> std::vec::IntoIter<&'static Country> {
+        let mut countries: Vec<&'static Country> = COUNTRIES
+            .iter()
+            .filter(|country| self.statuses.contains(&country.status()))
+            .filter(|country| self.territories || !country.is_territory())
+            .collect();
+
+        if let CountryOrder::Name(locale) = self.order {
+            let mut keyed: Vec<(String, &'static str, &'static Country)> = countries
+                .into_iter()
+                .map(|country| {
+                    let name = country.name_in(locale);
+                    (collation_key(country.code(), name, locale), name, country)
+                })
+                .collect();
+            keyed.sort_by(|left, right| (&left.0, left.1).cmp(&(&right.0, right.1)));
+            countries = keyed.into_iter().map(|(_, _, country)| country).collect();
+        }
+
+        countries.into_iter()
+    }
+}
+
+impl Default for CountryList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntoIterator for &CountryList {
+    type Item = &'static Country;
+    type IntoIter = std::vec::IntoIter<&'static Country>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns every entry of the built-in dataset, ordered by ISO 3166-1 alpha-2 code.
+///
+/// This includes reserved and legacy codes such as `EU` and `AN`. Use [`CountryList`] to
+/// filter entries by status or order them by name.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::all_countries;
+///
+/// let first = all_countries().next().unwrap();
+/// assert_eq!(first.code(), "AC");
+/// assert_eq!(first.name(), "Ascension Island");
+///
+/// assert!(all_countries().any(|country| country.code() == "AN"));
+/// assert_eq!(all_countries().count(), 260);
+/// ```
+pub fn all_countries() -> impl ExactSizeIterator<Item = &'static Country> {
+    COUNTRIES.iter()
+}
+
+/// Returns the officially assigned countries sorted alphabetically by their name in `locale`,
+/// for building dropdowns and pickers.
+///
+/// Names are compared ignoring case, diacritics, and an abbreviated "St.", so that "Åland
+/// Islands" follows "Afghanistan" and "Côte d'Ivoire" follows "Costa Rica" rather than landing
+/// after "Zimbabwe". Languages whose alphabets order letters differently are tailored:
+///
+/// - Swedish sorts å, ä, and ö, in that order, after z.
+/// - Spanish sorts ñ after n.
+/// - Russian, Arabic, and Japanese names are compared in their own script rather than
+///   transliterated, so that Cyrillic, Arabic, and kana keep their alphabetical order.
+///   Japanese names written with kanji, such as "日本", are compared by their kana reading.
+/// - Chinese names are compared by their pinyin.
+///
+/// Use [`CountryList`] with [`CountryOrder::Name`] to list other statuses in the same order.
+///
+/// # Arguments
+/// * `locale` - The language of the names to sort by
+///
+/// # Returns
+/// The countries in alphabetical order of [`Country::name_in`]
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{sorted_by_name, Locale};
+///
+/// let names: Vec<_> = sorted_by_name(Locale::En)
+///     .into_iter()
+///     .map(|country| country.name())
+///     .collect();
+///
+/// assert_eq!(&names[..3], ["Afghanistan", "Åland Islands", "Albania"]);
+/// let position = |name| names.iter().position(|&other| other == name).unwrap();
+/// assert_eq!(position("Côte d'Ivoire"), position("Costa Rica") + 1);
+/// assert_eq!(position("Saint Barthélemy"), position("Rwanda") + 1);
+///
+/// # #[cfg(feature = "locale-sv")]
+/// # {
+/// // Swedish sorts Å after Z.
+/// let swedish: Vec<_> = sorted_by_name(Locale::Sv)
+///     .into_iter()
+///     .map(|country| country.name_in(Locale::Sv))
+///     .collect();
+/// assert_eq!(swedish.last(), Some(&"Östtimor"));
+/// # }
+/// ```
+pub fn sorted_by_name(locale: Locale) -> Vec<&'static Country> {
+    CountryList::new()
+        .order(CountryOrder::Name(locale))
+        .iter()
+        .collect()
+}
+
+/// Returns a key that sorts the name of the country with `code` in alphabetical order for
+/// `locale`.
+#[cfg_attr(not(feature = "locale-ja"), allow(unused_variables))]
+fn collation_key(code: &str, name: &str, locale: Locale) -> String {
+    match locale {
+        Locale::En => normalize_text(name),
+        // Arabic letters are encoded in alphabetical order, with alef written in several forms.
+        #[cfg(feature = "locale-ar")]
+        Locale::Ar => name
+            .chars()
+            .map(|letter| match letter {
+                'آ' | 'أ' | 'إ' => 'ا',
+                _ => letter,
+            })
+            .collect(),
+        #[cfg(feature = "locale-de")]
+        Locale::De => normalize_text(name),
+        #[cfg(feature = "locale-es")]
+        Locale::Es => tailored_key(name, &[('ñ', "n~")]),
+        #[cfg(feature = "locale-fr")]
+        Locale::Fr => normalize_text(name),
+        // Names written with kanji sort by their reading.
+        #[cfg(feature = "locale-ja")]
+        Locale::Ja => kana_key(japanese_reading(code).unwrap_or(name)),
+        #[cfg(feature = "locale-pt")]
+        Locale::Pt => normalize_text(name),
+        // Cyrillic letters are encoded in alphabetical order, except "ё", which sorts as "е".
+        #[cfg(feature = "locale-ru")]
+        Locale::Ru => name.to_lowercase().replace('ё', "е"),
+        #[cfg(feature = "locale-sv")]
+        Locale::Sv => tailored_key(
+            name,
+            &[('å', "{"), ('ä', "|"), ('æ', "|"), ('ö', "}"), ('ø', "}")],
+        ),
+        // The transliteration of Chinese characters is their pinyin.
+        #[cfg(feature = "locale-zh")]
+        Locale::Zh => normalize_text(name),
+    }
+}
+
+// Katakana code points follow the gojūon order once small and voiced kana are folded into
+// their plain forms. Long vowel marks and middle dots are ignored.
+#[cfg(feature = "locale-ja")]
+fn kana_key(name: &str) -> String {
+    const FOLDED_KANA: &[(char, char)] = &[
+        ('ァ', 'ア'),
+        ('ィ', 'イ'),
+        ('ゥ', 'ウ'),
+        ('ェ', 'エ'),
+        ('ォ', 'オ'),
+        ('ガ', 'カ'),
+        ('ギ', 'キ'),
+        ('グ', 'ク'),
+        ('ゲ', 'ケ'),
+        ('ゴ', 'コ'),
+        ('ザ', 'サ'),
+        ('ジ', 'シ'),
+        ('ズ', 'ス'),
+        ('ゼ', 'セ'),
+        ('ゾ', 'ソ'),
+        ('ダ', 'タ'),
+        ('ヂ', 'チ'),
+        ('ッ', 'ツ'),
+        ('ヅ', 'ツ'),
+        ('デ', 'テ'),
+        ('ド', 'ト'),
+        ('バ', 'ハ'),
+        ('パ', 'ハ'),
+        ('ビ', 'ヒ'),
+        ('ピ', 'ヒ'),
+        ('ブ', 'フ'),
+        ('プ', 'フ'),
+        ('ベ', 'ヘ'),
+        ('ペ', 'ヘ'),
+        ('ボ', 'ホ'),
+        ('ポ', 'ホ'),
+        ('ャ', 'ヤ'),
+        ('ュ', 'ユ'),
+        ('ョ', 'ヨ'),
+        ('ヮ', 'ワ'),
+        ('ヴ', 'ウ'),
+    ];
+
+    name.chars()
+        .filter(|&kana| !matches!(kana, 'ー' | '・'))
+        .map(|kana| {
+            FOLDED_KANA
+                .iter()
+                .find(|&&(folded, _)| folded == kana)
+                .map_or(kana, |&(_, plain)| plain)
+        })
+        .collect()
+}
+
+// Replaces letters that a language sorts apart from their base letter with ASCII that sorts
+// after "z", such as "{" for the Swedish "å", before folding the remaining diacritics.
+#[cfg(any(feature = "locale-es", feature = "locale-sv"))]
+fn tailored_key(name: &str, letters: &[(char, &str)]) -> String {
+    let mut tailored = String::with_capacity(name.len());
+    for letter in name.to_lowercase().chars() {
+        match letters
+            .iter()
+            .find(|&&(tailored_letter, _)| tailored_letter == letter)
+        {
+            Some((_, replacement)) => tailored.push_str(replacement),
+            None => tailored.push(letter),
+        }
+    }
+    normalize_text(&tailored)
+}
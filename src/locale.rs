@@ -0,0 +1,230 @@
+use crate::countries::{country_code_index_from_bytes, COUNTRY_CODE_INDEX_SIZE};
+use crate::{country_code_index, get_by_code, Country};
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::str::FromStr;
+
+// The name tables start from the iso-codes translations and are adjusted to the CLDR short
+// display forms, so that for example KR is "Südkorea" rather than "Korea, Republik".
+#[cfg(feature = "locale-ar")]
+mod ar;
+#[cfg(feature = "locale-de")]
+mod de;
+#[cfg(feature = "locale-es")]
+mod es;
+#[cfg(feature = "locale-fr")]
+mod fr;
+#[cfg(feature = "locale-ja")]
+mod ja;
+#[cfg(feature = "locale-pt")]
+mod pt;
+#[cfg(feature = "locale-ru")]
+mod ru;
+#[cfg(feature = "locale-sv")]
+mod sv;
+#[cfg(feature = "locale-zh")]
+mod zh;
+
+type LocalizedNames = [Option<&'static str>; COUNTRY_CODE_INDEX_SIZE];
+
+/// A language for localized country names.
+///
+/// English uses the built-in dataset and is always available. Every other language is
+/// enabled by its own cargo feature, such as `locale-de`, or all at once with `locales`.
+/// Names follow the short display forms used by the Unicode CLDR, and the names of every
+/// enabled language are also recognized by [`name_to_code`](crate::name_to_code).
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{code_to_name_in, Locale};
+///
+/// let locale: Locale = "en-GB".parse().unwrap();
+/// assert_eq!(locale, Locale::En);
+/// assert_eq!(code_to_name_in("DE", locale), Some("Germany"));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English.
+    En,
+    /// Arabic. Requires the `locale-ar` feature.
+    #[cfg(feature = "locale-ar")]
+    Ar,
+    /// German. Requires the `locale-de` feature.
+    #[cfg(feature = "locale-de")]
+    De,
+    /// Spanish. Requires the `locale-es` feature.
+    #[cfg(feature = "locale-es")]
+    Es,
+    /// French. Requires the `locale-fr` feature.
+    #[cfg(feature = "locale-fr")]
+    Fr,
+    /// Japanese. Requires the `locale-ja` feature.
+    #[cfg(feature = "locale-ja")]
+    Ja,
+    /// Portuguese, using Brazilian spellings. Requires the `locale-pt` feature.
+    #[cfg(feature = "locale-pt")]
+    Pt,
+    /// Russian. Requires the `locale-ru` feature.
+    #[cfg(feature = "locale-ru")]
+    Ru,
+    /// Swedish. Requires the `locale-sv` feature.
+    #[cfg(feature = "locale-sv")]
+    Sv,
+    /// Simplified Chinese. Requires the `locale-zh` feature.
+    #[cfg(feature = "locale-zh")]
+    Zh,
+}
+
+impl Locale {
+    /// Every enabled locale, in alphabetical order of language code.
+    pub const ALL: &'static [Locale] = &[
+        #[cfg(feature = "locale-ar")]
+        Locale::Ar,
+        #[cfg(feature = "locale-de")]
+        Locale::De,
+        Locale::En,
+        #[cfg(feature = "locale-es")]
+        Locale::Es,
+        #[cfg(feature = "locale-fr")]
+        Locale::Fr,
+        #[cfg(feature = "locale-ja")]
+        Locale::Ja,
+        #[cfg(feature = "locale-pt")]
+        Locale::Pt,
+        #[cfg(feature = "locale-ru")]
+        Locale::Ru,
+        #[cfg(feature = "locale-sv")]
+        Locale::Sv,
+        #[cfg(feature = "locale-zh")]
+        Locale::Zh,
+    ];
+
+    /// Returns the lowercase ISO 639-1 language code, such as `"de"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            #[cfg(feature = "locale-ar")]
+            Locale::Ar => "ar",
+            #[cfg(feature = "locale-de")]
+            Locale::De => "de",
+            #[cfg(feature = "locale-es")]
+            Locale::Es => "es",
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr => "fr",
+            #[cfg(feature = "locale-ja")]
+            Locale::Ja => "ja",
+            #[cfg(feature = "locale-pt")]
+            Locale::Pt => "pt",
+            #[cfg(feature = "locale-ru")]
+            Locale::Ru => "ru",
+            #[cfg(feature = "locale-sv")]
+            Locale::Sv => "sv",
+            #[cfg(feature = "locale-zh")]
+            Locale::Zh => "zh",
+        }
+    }
+
+    /// Returns the `(code, name)` pairs for this locale, or `None` for English, whose names
+    /// come from the built-in dataset.
+    pub(crate) fn names(self) -> Option<&'static [(&'static str, &'static str)]> {
+        match self {
+            Locale::En => None,
+            #[cfg(feature = "locale-ar")]
+            Locale::Ar => Some(ar::NAMES),
+            #[cfg(feature = "locale-de")]
+            Locale::De => Some(de::NAMES),
+            #[cfg(feature = "locale-es")]
+            Locale::Es => Some(es::NAMES),
+            #[cfg(feature = "locale-fr")]
+            Locale::Fr => Some(fr::NAMES),
+            #[cfg(feature = "locale-ja")]
+            Locale::Ja => Some(ja::NAMES),
+            #[cfg(feature = "locale-pt")]
+            Locale::Pt => Some(pt::NAMES),
+            #[cfg(feature = "locale-ru")]
+            Locale::Ru => Some(ru::NAMES),
+            #[cfg(feature = "locale-sv")]
+            Locale::Sv => Some(sv::NAMES),
+            #[cfg(feature = "locale-zh")]
+            Locale::Zh => Some(zh::NAMES),
+        }
+    }
+
+    fn name_index(self) -> Option<&'static LocalizedNames> {
+        // One lazily built table per locale, in the same order as `Locale::ALL`.
+        static NAME_INDEXES: Lazy<Vec<LocalizedNames>> = Lazy::new(|| {
+            Locale::ALL
+                .iter()
+                .map(|locale| {
+                    let mut names_by_code = [None; COUNTRY_CODE_INDEX_SIZE];
+                    for &(code, name) in locale.names().unwrap_or_default() {
+                        if let Some(index) = country_code_index_from_bytes(code.as_bytes()) {
+                            names_by_code[index] = Some(name);
+                        }
+                    }
+                    names_by_code
+                })
+                .collect()
+        });
+
+        self.names()?;
+        let position = Locale::ALL.iter().position(|&locale| locale == self)?;
+        Some(&NAME_INDEXES[position])
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when a string does not name an enabled [`Locale`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLocaleError {
+    input: String,
+}
+
+impl fmt::Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown or disabled locale \"{}\"", self.input)
+    }
+}
+
+impl std::error::Error for ParseLocaleError {}
+
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    /// Parses a BCP 47 language tag such as `"de"`, `"pt-BR"`, or `"zh_Hans"`. Only the
+    /// language subtag is considered.
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let language = tag.trim().split(['-', '_']).next().unwrap_or_default();
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.as_str().eq_ignore_ascii_case(language))
+            .ok_or_else(|| ParseLocaleError {
+                input: tag.to_string(),
+            })
+    }
+}
+
+pub(crate) fn localized_name(code: &str, locale: Locale) -> Option<&'static str> {
+    match locale.name_index() {
+        Some(names_by_code) => country_code_index(code).and_then(|index| names_by_code[index]),
+        None => get_by_code(code).map(Country::name),
+    }
+}
+
+/// Returns the katakana reading of a Japanese name written with kanji, such as "チュウゴク" for
+/// "中国".
+#[cfg(feature = "locale-ja")]
+pub(crate) fn japanese_reading(code: &str) -> Option<&'static str> {
+    ja::READINGS
+        .iter()
+        .find(|&&(reading_code, _)| reading_code == code)
+        .map(|&(_, reading)| reading)
+}
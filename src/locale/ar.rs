@@ -0,0 +1,264 @@
+//! Arabic country names.
+
+pub(super) const NAMES: &[(&str, &str)] = &[
+    ("AC", "جزيرة أسينشين"),
+    ("AD", "أندورا"),
+    ("AE", "الإمارات العربية المتحدة"),
+    ("AF", "أفغانستان"),
+    ("AG", "أنتيغوا وباربودا"),
+    ("AI", "أنغويلا"),
+    ("AL", "ألبانيا"),
+    ("AM", "أرمينيا"),
+    ("AN", "جزر الأنتيل الهولندية"),
+    ("AO", "أنغولا"),
+    ("AQ", "القطب الجنوبي"),
+    ("AR", "الأرجنتين"),
+    ("AS", "صاموا الأمريكية"),
+    ("AT", "النمسا"),
+    ("AU", "أستراليا"),
+    ("AW", "أروبا"),
+    ("AX", "جزر آلاند"),
+    ("AZ", "أذربيجان"),
+    ("BA", "البوسنة والهرسك"),
+    ("BB", "بربادوس"),
+    ("BD", "بنغلادش"),
+    ("BE", "بلجيكا"),
+    ("BF", "بوركينا فاصو"),
+    ("BG", "بلغاريا"),
+    ("BH", "البحرين"),
+    ("BI", "بوروندي"),
+    ("BJ", "بنين"),
+    ("BL", "سان بارتليمي"),
+    ("BM", "برمودا"),
+    ("BN", "بروناي"),
+    ("BO", "بوليفيا"),
+    ("BQ", "هولندا الكاريبية"),
+    ("BR", "البرازيل"),
+    ("BS", "جزر البهاما"),
+    ("BT", "بوتان"),
+    ("BV", "جزيرة بوفي"),
+    ("BW", "بوتسوانا"),
+    ("BY", "بيلاروس"),
+    ("BZ", "بيليز"),
+    ("CA", "كندا"),
+    ("CC", "جزر الكوكوس"),
+    ("CD", "الكونغو - كينشاسا"),
+    ("CF", "جمهورية أفريقيا الوسطى"),
+    ("CG", "الكونغو - برازافيل"),
+    ("CH", "سويسرا"),
+    ("CI", "ساحل العاج"),
+    ("CK", "جزر كوك"),
+    ("CL", "تشيلي"),
+    ("CM", "الكاميرون"),
+    ("CN", "الصين"),
+    ("CO", "كولومبيا"),
+    ("CP", "جزيرة كليبيرتون"),
+    ("CQ", "سارك"),
+    ("CR", "كوستاريكا"),
+    ("CU", "كوبا"),
+    ("CV", "الرأس الأخضر"),
+    ("CW", "كوراساو"),
+    ("CX", "جزيرة كريسماس"),
+    ("CY", "قبرص"),
+    ("CZ", "التشيك"),
+    ("DE", "ألمانيا"),
+    ("DG", "دييغو غارسيا"),
+    ("DJ", "جيبوتي"),
+    ("DK", "الدانمرك"),
+    ("DM", "دومينيكا"),
+    ("DO", "جمهورية الدومينيكان"),
+    ("DZ", "الجزائر"),
+    ("EA", "سبتة ومليلية"),
+    ("EC", "الإكوادور"),
+    ("EE", "إستونيا"),
+    ("EG", "مصر"),
+    ("EH", "الصحراء الغربية"),
+    ("ER", "إريتريا"),
+    ("ES", "إسبانيا"),
+    ("ET", "إثيوبيا"),
+    ("EU", "الاتحاد الأوروبي"),
+    ("FI", "فنلندا"),
+    ("FJ", "فيجي"),
+    ("FK", "جزر فوكلاند"),
+    ("FM", "ميكرونيزيا"),
+    ("FO", "جزر الفارو"),
+    ("FR", "فرنسا"),
+    ("GA", "الغابون"),
+    ("GB", "المملكة المتحدة"),
+    ("GD", "غرينادا"),
+    ("GE", "جورجيا"),
+    ("GF", "غيانا الفرنسية"),
+    ("GG", "غيرنزي"),
+    ("GH", "غانا"),
+    ("GI", "جبل طارق"),
+    ("GL", "غرينلاند"),
+    ("GM", "غامبيا"),
+    ("GN", "غينيا"),
+    ("GP", "غوادلوب"),
+    ("GQ", "غينيا الاستوائية"),
+    ("GR", "اليونان"),
+    ("GS", "جورجيا الجنوبية وجزر ساندويتش الجنوبية"),
+    ("GT", "غواتيمالا"),
+    ("GU", "غوام"),
+    ("GW", "غينيا بيساو"),
+    ("GY", "غويانا"),
+    ("HK", "هونغ كونغ"),
+    ("HM", "جزيرة هيرد وجزر ماكدونالد"),
+    ("HN", "هندوراس"),
+    ("HR", "كرواتيا"),
+    ("HT", "هايتي"),
+    ("HU", "هنغاريا"),
+    ("IC", "جزر الكناري"),
+    ("ID", "إندونيسيا"),
+    ("IE", "أيرلندا"),
+    ("IL", "إسرائيل"),
+    ("IM", "جزيرة مان"),
+    ("IN", "الهند"),
+    ("IO", "الإقليم البريطاني في المحيط الهندي"),
+    ("IQ", "العراق"),
+    ("IR", "إيران"),
+    ("IS", "آيسلندا"),
+    ("IT", "إيطاليا"),
+    ("JE", "جيرسي"),
+    ("JM", "جامايكا"),
+    ("JO", "الأردن"),
+    ("JP", "اليابان"),
+    ("KE", "كينيا"),
+    ("KG", "قيرغزستان"),
+    ("KH", "كمبوديا"),
+    ("KI", "كيريباتي"),
+    ("KM", "جزر القمر"),
+    ("KN", "سانت كيتس ونيفيس"),
+    ("KP", "كوريا الشمالية"),
+    ("KR", "كوريا الجنوبية"),
+    ("KW", "الكويت"),
+    ("KY", "جزر الكيمان"),
+    ("KZ", "كازاخستان"),
+    ("LA", "لاوس"),
+    ("LB", "لبنان"),
+    ("LC", "سانت لوسيا"),
+    ("LI", "ليشتنشتاين"),
+    ("LK", "سريلانكا"),
+    ("LR", "ليبيريا"),
+    ("LS", "ليسوتو"),
+    ("LT", "ليتوانيا"),
+    ("LU", "لوكسمبورغ"),
+    ("LV", "لاتفيا"),
+    ("LY", "ليبيا"),
+    ("MA", "المغرب"),
+    ("MC", "موناكو"),
+    ("MD", "مولدوفا"),
+    ("ME", "الجبل الأسود"),
+    ("MF", "سان مارتن"),
+    ("MG", "مدغشقر"),
+    ("MH", "جزر المارشال"),
+    ("MK", "مقدونيا الشمالية"),
+    ("ML", "مالي"),
+    ("MM", "ميانمار"),
+    ("MN", "منغوليا"),
+    ("MO", "مكاو"),
+    ("MP", "جزر ماريانا الشمالية"),
+    ("MQ", "مارتينيك"),
+    ("MR", "موريتانيا"),
+    ("MS", "مونتسيرات"),
+    ("MT", "مالطة"),
+    ("MU", "موريشيوس"),
+    ("MV", "جزر المالديف"),
+    ("MW", "ملاوي"),
+    ("MX", "المكسيك"),
+    ("MY", "ماليزيا"),
+    ("MZ", "موزمبيق"),
+    ("NA", "ناميبيا"),
+    ("NC", "كاليدونيا الجديدة"),
+    ("NE", "النيجر"),
+    ("NF", "جزيرة نورفولك"),
+    ("NG", "نيجيريا"),
+    ("NI", "نيكاراغوا"),
+    ("NL", "هولندا"),
+    ("NO", "النرويج"),
+    ("NP", "نيبال"),
+    ("NR", "ناورو"),
+    ("NU", "نيوي"),
+    ("NZ", "نيوزيلاندا"),
+    ("OM", "عمان"),
+    ("PA", "بنما"),
+    ("PE", "بيرو"),
+    ("PF", "بولينيسيا الفرنسية"),
+    ("PG", "بابوا غينيا الجديدة"),
+    ("PH", "الفلبين"),
+    ("PK", "باكستان"),
+    ("PL", "بولندا"),
+    ("PM", "سان بيير وميكولون"),
+    ("PN", "جزر بيتكيرن"),
+    ("PR", "بورتوريكو"),
+    ("PS", "الأراضي الفلسطينية"),
+    ("PT", "البرتغال"),
+    ("PW", "بالاو"),
+    ("PY", "باراغواي"),
+    ("QA", "قطر"),
+    ("RE", "ريونيون"),
+    ("RO", "رومانيا"),
+    ("RS", "صربيا"),
+    ("RU", "روسيا"),
+    ("RW", "رواندا"),
+    ("SA", "المملكة العربية السعودية"),
+    ("SB", "جزر سولومن"),
+    ("SC", "سيشل"),
+    ("SD", "السودان"),
+    ("SE", "السويد"),
+    ("SG", "سنغافورة"),
+    ("SH", "سانت هيلينا"),
+    ("SI", "سلوفينيا"),
+    ("SJ", "سفالبارد وجان ماين"),
+    ("SK", "سلوفاكيا"),
+    ("SL", "سيراليون"),
+    ("SM", "سان مارينو"),
+    ("SN", "السنغال"),
+    ("SO", "الصومال"),
+    ("SR", "سورينام"),
+    ("SS", "جنوب السودان"),
+    ("ST", "ساو تومي وبرينسيبي"),
+    ("SV", "السلفادور"),
+    ("SX", "سانت مارتن (الجزء الهولندي)"),
+    ("SY", "سوريا"),
+    ("SZ", "إسواتيني"),
+    ("TA", "تريستان دا كونا"),
+    ("TC", "جزر توركس وكايكوس"),
+    ("TD", "تشاد"),
+    ("TF", "الأقاليم الجنوبية الفرنسية"),
+    ("TG", "توغو"),
+    ("TH", "تايلاند"),
+    ("TJ", "طاجيكستان"),
+    ("TK", "توكيلو"),
+    ("TL", "تيمور-ليستي"),
+    ("TM", "تركمانستان"),
+    ("TN", "تونس"),
+    ("TO", "تونغا"),
+    ("TR", "تركيا"),
+    ("TT", "ترينيداد وتوباغو"),
+    ("TV", "توفالو"),
+    ("TW", "تايوان"),
+    ("TZ", "تنزانيا"),
+    ("UA", "أوكرانيا"),
+    ("UG", "أوغندا"),
+    ("UM", "جزر الولايات المتحدة النائية"),
+    ("UN", "الأمم المتحدة"),
+    ("US", "الولايات المتحدة"),
+    ("UY", "أوروغواي"),
+    ("UZ", "أوزبكستان"),
+    ("VA", "الفاتيكان"),
+    ("VC", "سانت فنسنت وجزر غرينادين"),
+    ("VE", "فنزويلا"),
+    ("VG", "جزر فيرجين البريطانية"),
+    ("VI", "جزر فيرجين التابعة للولايات المتحدة"),
+    ("VN", "فيتنام"),
+    ("VU", "فانواتو"),
+    ("WF", "جزر والس وفوتونا"),
+    ("WS", "صاموا"),
+    ("XK", "كوسوفو"),
+    ("YE", "اليمن"),
+    ("YT", "مايوت"),
+    ("ZA", "جنوب إفريقيا"),
+    ("ZM", "زامبيا"),
+    ("ZW", "زمبابوي"),
+];
@@ -0,0 +1,525 @@
+use crate::subdivision::SUBDIVISIONS_INDEX;
+use crate::{
+    ambiguous_term, best_fuzzy_match, candidate_indices_for, demonym_match, direct_name_match,
+    flag_to_code, get_by_code_input, get_flag_subdivision, government_pattern_demonym_match,
+    government_pattern_match, is_ambiguous_term, lookup_country_name, normalize_text,
+    scored_candidates, should_reject_fuzzy_match, Country, LookupError, Match, MatchKind,
+    NameIndex, AMBIGUITY_MARGIN, COUNTRIES_INDEX,
+};
+use std::borrow::Cow;
+
+const DEFAULT_SINGLE_WORD_THRESHOLD: f32 = 0.4;
+const DEFAULT_MULTI_WORD_THRESHOLD: f32 = 0.2;
+
+pub(crate) static DEFAULT_MATCHER: Matcher = Matcher::new();
+
+/// A configurable country-name matcher.
+///
+/// The free functions such as [`name_to_code`](crate::name_to_code) and
+/// [`name_match`](crate::name_match) use [`Matcher::default`]. Build a custom matcher with
+/// [`Matcher::builder`] to tune fuzzy thresholds, disable fuzzy or typo-tolerant matching,
+/// skip comma reversal or government-title stripping, ignore demonyms, or treat additional words
+/// as too generic to match on.
+///
+/// The options only affect how the input is interpreted. Known names, aliases, and their
+/// indexed variants are always recognized.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{name_to_code, Matcher};
+///
+/// let matcher = Matcher::builder().fuzzy(false).build();
+///
+/// assert_eq!(matcher.name_to_code("Germany"), Some("DE"));
+/// assert_eq!(name_to_code("Holy See (Vatican City State)"), Some("VA"));
+/// assert_eq!(matcher.name_to_code("Holy See (Vatican City State)"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matcher {
+    single_word_threshold: f32,
+    multi_word_threshold: f32,
+    fuzzy: bool,
+    pub(crate) typo_tolerance: bool,
+    pub(crate) comma_reversal: bool,
+    pub(crate) government_patterns: bool,
+    pub(crate) demonyms: bool,
+    generic_words: Vec<String>,
+}
+
+impl Matcher {
+    /// Returns a matcher with the default configuration used by the free functions.
+    pub const fn new() -> Self {
+        Self {
+            single_word_threshold: DEFAULT_SINGLE_WORD_THRESHOLD,
+            multi_word_threshold: DEFAULT_MULTI_WORD_THRESHOLD,
+            fuzzy: true,
+            typo_tolerance: true,
+            comma_reversal: true,
+            government_patterns: true,
+            demonyms: true,
+            generic_words: Vec::new(),
+        }
+    }
+
+    /// Returns a builder starting from the default configuration.
+    pub fn builder() -> MatcherBuilder {
+        MatcherBuilder::default()
+    }
+
+    /// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1
+    /// alpha-2 code.
+    ///
+    /// Behaves like [`code`](crate::code), using this matcher for text input.
+    pub fn code(&self, input: &str) -> Option<&'static str> {
+        flag_to_code(input)
+            .or_else(|| get_by_code_input(input).map(Country::code))
+            .or_else(|| get_flag_subdivision(input))
+            .or_else(|| self.name_to_code(input))
+    }
+
+    /// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1
+    /// alpha-2 code, explaining why the lookup failed.
+    ///
+    /// Behaves like [`try_code`](crate::try_code), using this matcher for text input.
+    pub fn try_code(&self, input: &str) -> Result<&'static str, LookupError> {
+        match flag_to_code(input)
+            .or_else(|| get_by_code_input(input).map(Country::code))
+            .or_else(|| get_flag_subdivision(input))
+        {
+            Some(code) => Ok(code),
+            None => self.try_name_to_code(input),
+        }
+    }
+
+    /// Resolves country-like text to an ISO 3166-1 alpha-2 code.
+    ///
+    /// Behaves like [`name_to_code`](crate::name_to_code) with this matcher's configuration.
+    pub fn name_to_code(&self, name: &str) -> Option<&'static str> {
+        self.name_match(name).map(|name_match| name_match.code())
+    }
+
+    /// Resolves country-like text and reports how confident the match is.
+    ///
+    /// Behaves like [`name_match`](crate::name_match) with this matcher's configuration.
+    pub fn name_match(&self, name: &str) -> Option<Match> {
+        self.name_match_in(&COUNTRIES_INDEX, name)
+    }
+
+    /// Returns the best-scoring country codes for country-like text, highest score first.
+    ///
+    /// Behaves like [`name_candidates`](crate::name_candidates) with this matcher's
+    /// configuration. Candidates are returned even when fuzzy matching is disabled.
+    pub fn name_candidates(&self, input: &str, limit: usize) -> Vec<(&'static str, f32)> {
+        self.name_candidates_in(&COUNTRIES_INDEX, input, limit)
+    }
+
+    /// Resolves country-like text to an ISO 3166-1 alpha-2 code, explaining why the lookup
+    /// failed.
+    ///
+    /// Behaves like [`try_name_to_code`](crate::try_name_to_code) with this matcher's
+    /// configuration.
+    pub fn try_name_to_code(&self, name: &str) -> Result<&'static str, LookupError> {
+        self.try_name_to_code_in(&COUNTRIES_INDEX, name)
+    }
+
+    /// Resolves a subdivision name to its ISO 3166-2 code.
+    ///
+    /// Behaves like [`subdivision_name_to_code`](crate::subdivision_name_to_code) with this
+    /// matcher's configuration.
+    pub fn subdivision_name_to_code(&self, name: &str) -> Option<&'static str> {
+        let found = self.name_match_in(&SUBDIVISIONS_INDEX, name)?;
+        if found.kind() == MatchKind::Fuzzy
+            && self.has_close_rival(&SUBDIVISIONS_INDEX, name, &found)
+        {
+            return None;
+        }
+        Some(found.code())
+    }
+
+    pub(crate) fn name_match_in(&self, index: &NameIndex, name: &str) -> Option<Match> {
+        let found = self.resolve_in(index, name)?;
+        self.rivals(index, name, &found).is_none().then_some(found)
+    }
+
+    // Runs the matching stages, without rejecting matches that other countries share.
+    fn resolve_in(&self, index: &NameIndex, name: &str) -> Option<Match> {
+        let trimmed_input = name.trim();
+        if trimmed_input.is_empty() {
+            return None;
+        }
+
+        if let Some(code) = direct_name_match(index, trimmed_input) {
+            return Some(Match::new(code, 1.0, MatchKind::Exact));
+        }
+
+        let normalized_input = normalize_text(trimmed_input);
+        if let Some(code) = lookup_country_name(index, &normalized_input) {
+            return Some(Match::new(code, 1.0, MatchKind::Normalized));
+        }
+
+        // A known demonym never falls through to fuzzy matching, where "American" would
+        // resolve to American Samoa once demonyms are disabled.
+        if let Some(code) = demonym_match(index, trimmed_input) {
+            return self
+                .demonyms
+                .then(|| Match::new(code, 1.0, MatchKind::Demonym));
+        }
+
+        if let Some(code) = government_pattern_match(index, self, &normalized_input) {
+            return Some(Match::new(code, 1.0, MatchKind::GovernmentPattern));
+        }
+
+        if let Some(code) = government_pattern_demonym_match(index, self, &normalized_input) {
+            return self
+                .demonyms
+                .then(|| Match::new(code, 1.0, MatchKind::Demonym));
+        }
+
+        if !self.fuzzy {
+            return None;
+        }
+
+        let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
+        if should_reject_fuzzy_match(index, self, &input_words) {
+            return None;
+        }
+
+        best_fuzzy_match(index, self, &normalized_input, &input_words)
+            .filter(|(_, score)| *score >= self.fuzzy_threshold(&input_words))
+            .map(|(code, score)| Match::new(code, score, MatchKind::Fuzzy))
+    }
+
+    pub(crate) fn name_candidates_in(
+        &self,
+        index: &NameIndex,
+        input: &str,
+        limit: usize,
+    ) -> Vec<(&'static str, f32)> {
+        let trimmed_input = input.trim();
+        if trimmed_input.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let normalized_input = normalize_text(trimmed_input);
+        let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
+        let exact_match = direct_name_match(index, trimmed_input)
+            .or_else(|| lookup_country_name(index, &normalized_input))
+            .or_else(|| demonym_match(index, trimmed_input).filter(|_| self.demonyms))
+            .or_else(|| government_pattern_match(index, self, &normalized_input))
+            .or_else(|| {
+                government_pattern_demonym_match(index, self, &normalized_input)
+                    .filter(|_| self.demonyms)
+            });
+
+        let mut candidates: Vec<(&'static str, f32)> = exact_match
+            .map(|code| (code, 1.0))
+            .into_iter()
+            .chain(
+                scored_candidates(
+                    index,
+                    self,
+                    &normalized_input,
+                    &input_words,
+                    Cow::Borrowed(index.all_indices.as_slice()),
+                )
+                .filter(|&(code, score)| score > 0.0 && Some(code) != exact_match),
+            )
+            .collect();
+
+        candidates.sort_by(|left, right| right.1.total_cmp(&left.1));
+        candidates.truncate(limit);
+        candidates
+    }
+
+    pub(crate) fn try_name_to_code_in(
+        &self,
+        index: &NameIndex,
+        name: &str,
+    ) -> Result<&'static str, LookupError> {
+        let normalized_input = normalize_text(name);
+        let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
+        // Input that only names a term shared by several countries, such as "Korea" or "Congo",
+        // is ambiguous between all of them, however low they score.
+        let shared_term = input_words
+            .iter()
+            .all(|word| self.is_generic(index, word) || is_ambiguous_term(self, word));
+
+        if let Some(found) = self.resolve_in(index, name) {
+            return match self.rivals(index, name, &found) {
+                Some(candidates) => Err(LookupError::Ambiguous { candidates }),
+                None => Ok(found.code()),
+            };
+        }
+
+        if input_words.is_empty()
+            || demonym_match(index, name.trim()).is_some()
+            || government_pattern_demonym_match(index, self, &normalized_input).is_some()
+        {
+            return Err(LookupError::NotFound);
+        }
+
+        if should_reject_fuzzy_match(index, self, &input_words) {
+            return Err(LookupError::TooGeneric);
+        }
+
+        // Otherwise only candidates scoring like a fuzzy match are plausible.
+        let scored = self.scored(index, &normalized_input, &input_words);
+        let best_score = scored
+            .iter()
+            .fold(0.0f32, |best, &(_, score)| best.max(score));
+        let candidates = sorted_codes(
+            scored
+                .into_iter()
+                .filter(|&(_, score)| shared_term || score >= best_score - AMBIGUITY_MARGIN)
+                .collect(),
+        );
+
+        if candidates.len() > 1 && (shared_term || best_score >= self.fuzzy_threshold(&input_words))
+        {
+            Err(LookupError::Ambiguous { candidates })
+        } else {
+            Err(LookupError::NotFound)
+        }
+    }
+
+    // Returns every country a match is shared with, itself included, when the input does not
+    // single it out.
+    fn rivals(&self, index: &NameIndex, name: &str, found: &Match) -> Option<Vec<&'static str>> {
+        let normalized_input = normalize_text(name);
+
+        // A fuzzy match resting on a shared term, as CD does for "Congo", is only one of the
+        // countries named by it, unless another word such as "Democratic" singles it out.
+        if found.kind() == MatchKind::Fuzzy {
+            let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
+            if let Some(codes) = self
+                .shared_term_codes(index, &input_words)
+                .filter(|codes| codes.len() > 1)
+            {
+                return Some(codes);
+            }
+        }
+
+        // A localized name shared with another country, such as the Spanish "Congo" for CG,
+        // is ambiguous with the country it resolves to.
+        let shared = index.shared_name_codes(&normalized_input);
+        shared.iter().any(|&code| code != found.code()).then(|| {
+            sorted_codes(
+                shared
+                    .iter()
+                    .chain([&found.code()])
+                    .map(|&code| (code, 1.0))
+                    .collect(),
+            )
+        })
+    }
+
+    // Returns the countries with names containing every word of input made of shared terms and
+    // generic words, such as CD and CG for "Congo Republic".
+    fn shared_term_codes(
+        &self,
+        index: &NameIndex,
+        input_words: &[&str],
+    ) -> Option<Vec<&'static str>> {
+        let mut has_shared_term = false;
+        let mut codes: Option<Vec<(&'static str, f32)>> = None;
+        for &word in input_words {
+            let term = match ambiguous_term(self, word) {
+                Some(term) => {
+                    has_shared_term = true;
+                    term
+                }
+                None if self.is_generic(index, word) => word,
+                None => return None,
+            };
+            let containing: Vec<&'static str> = index.codes_containing(&[term]).collect();
+            codes = Some(match codes {
+                None => containing.into_iter().map(|code| (code, 1.0)).collect(),
+                Some(codes) => codes
+                    .into_iter()
+                    .filter(|(code, _)| containing.contains(code))
+                    .collect(),
+            });
+        }
+        codes.filter(|_| has_shared_term).map(sorted_codes)
+    }
+
+    // The entries sharing a word with the input, with their fuzzy scores.
+    fn scored(
+        &self,
+        index: &NameIndex,
+        normalized_input: &str,
+        input_words: &[&str],
+    ) -> Vec<(&'static str, f32)> {
+        scored_candidates(
+            index,
+            self,
+            normalized_input,
+            input_words,
+            candidate_indices_for(index, self, input_words),
+        )
+        .filter(|&(_, score)| score > 0.0)
+        .collect()
+    }
+
+    // Whether another entry scores within the ambiguity margin of a fuzzy match, or also has a
+    // name containing the input, as "South Carolina" does for "Carolina".
+    fn has_close_rival(&self, index: &NameIndex, name: &str, found: &Match) -> bool {
+        let normalized_input = normalize_text(name.trim());
+        let input_words: Vec<&str> = normalized_input.split_whitespace().collect();
+        let has_rival = scored_candidates(
+            index,
+            self,
+            &normalized_input,
+            &input_words,
+            candidate_indices_for(index, self, &input_words),
+        )
+        .any(|(code, score)| code != found.code() && score >= found.score() - AMBIGUITY_MARGIN);
+        has_rival
+            || index
+                .codes_containing(&input_words)
+                .any(|code| code != found.code())
+    }
+
+    pub(crate) fn is_generic(&self, index: &NameIndex, word: &str) -> bool {
+        index.is_generic(word) || self.generic_words.iter().any(|generic| generic == word)
+    }
+
+    fn fuzzy_threshold(&self, input_words: &[&str]) -> f32 {
+        if input_words.len() == 1 {
+            self.single_word_threshold
+        } else {
+            self.multi_word_threshold
+        }
+    }
+}
+
+impl Default for Matcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds a [`Matcher`] with a custom configuration.
+///
+/// Every option starts at the default used by the free functions.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::{LookupError, Matcher};
+///
+/// let matcher = Matcher::builder()
+///     .single_word_threshold(0.6)
+///     .multi_word_threshold(0.5)
+///     .government_patterns(false)
+///     .generic_word("country")
+///     .build();
+///
+/// assert_eq!(matcher.name_to_code("Canada"), Some("CA"));
+/// assert_eq!(matcher.try_name_to_code("Country"), Err(LookupError::TooGeneric));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MatcherBuilder {
+    matcher: Matcher,
+}
+
+impl MatcherBuilder {
+    /// Sets the minimum fuzzy score accepted for single-word input. Defaults to `0.4`.
+    pub fn single_word_threshold(mut self, threshold: f32) -> Self {
+        self.matcher.single_word_threshold = threshold;
+        self
+    }
+
+    /// Sets the minimum fuzzy score accepted for multi-word input. Defaults to `0.2`.
+    pub fn multi_word_threshold(mut self, threshold: f32) -> Self {
+        self.matcher.multi_word_threshold = threshold;
+        self
+    }
+
+    /// Enables or disables fuzzy matching. Enabled by default.
+    ///
+    /// When disabled, only exact, normalized, and government-pattern matches succeed.
+    pub fn fuzzy(mut self, enabled: bool) -> Self {
+        self.matcher.fuzzy = enabled;
+        self
+    }
+
+    /// Enables or disables tolerating misspelled words such as `"Germny"` during fuzzy
+    /// matching. Enabled by default.
+    ///
+    /// Words of five to eight characters tolerate one edit, longer words two. An edit is an
+    /// insertion, deletion, substitution, or swap of adjacent letters.
+    pub fn typo_tolerance(mut self, enabled: bool) -> Self {
+        self.matcher.typo_tolerance = enabled;
+        self
+    }
+
+    /// Enables or disables reversing comma-separated input such as
+    /// `"Virgin Islands, British"`. Enabled by default.
+    pub fn comma_reversal(mut self, enabled: bool) -> Self {
+        self.matcher.comma_reversal = enabled;
+        self
+    }
+
+    /// Enables or disables stripping government titles such as `"Republic of"` from the
+    /// input. Enabled by default.
+    pub fn government_patterns(mut self, enabled: bool) -> Self {
+        self.matcher.government_patterns = enabled;
+        self
+    }
+
+    /// Enables or disables matching demonyms such as `"German"` or `"American"`. Enabled by
+    /// default.
+    ///
+    /// When disabled, demonyms resolve to nothing instead of falling through to fuzzy
+    /// matching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use country_emoji::{name_to_code, Matcher};
+    ///
+    /// let matcher = Matcher::builder().demonyms(false).build();
+    ///
+    /// assert_eq!(name_to_code("American"), Some("US"));
+    /// assert_eq!(matcher.name_to_code("American"), None);
+    /// assert_eq!(matcher.name_to_code("United States"), Some("US"));
+    /// ```
+    pub fn demonyms(mut self, enabled: bool) -> Self {
+        self.matcher.demonyms = enabled;
+        self
+    }
+
+    /// Treats an additional word as too generic to identify a country on its own.
+    ///
+    /// The word is normalized like country names, so case and diacritics do not matter.
+    pub fn generic_word(mut self, word: impl AsRef<str>) -> Self {
+        let normalized = normalize_text(word.as_ref());
+        if !normalized.is_empty() && !self.matcher.generic_words.contains(&normalized) {
+            self.matcher.generic_words.push(normalized);
+        }
+        self
+    }
+
+    /// Treats additional words as too generic to identify a country on their own.
+    pub fn generic_words<I>(self, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        words.into_iter().fold(self, Self::generic_word)
+    }
+
+    /// Builds the configured [`Matcher`].
+    pub fn build(self) -> Matcher {
+        self.matcher
+    }
+}
+
+fn sorted_codes(scored: Vec<(&'static str, f32)>) -> Vec<&'static str> {
+    let mut codes: Vec<&'static str> = scored.into_iter().map(|(code, _)| code).collect();
+    codes.sort_unstable();
+    codes.dedup();
+    codes
+}
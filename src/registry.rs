@@ -0,0 +1,307 @@
+use crate::countries::COUNTRIES;
+use crate::{
+    flag_to_code, get_by_code, get_by_code_input, normalize_text, Country, CountryCode,
+    LookupError, Match, Matcher, NameIndex, ParseCountryCodeError, COUNTRIES_INDEX,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[cfg(any(feature = "csv", feature = "json"))]
+mod loader;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use loader::{DataFormat, LoadError, LoadMode};
+
+// The names and demonyms a country starts with, preferred name first. Aliases added later
+// are kept apart so that rebuilding the index can register them last, in the order they were
+// added.
+#[derive(Clone)]
+struct RegistryEntry {
+    code: &'static str,
+    names: Vec<Cow<'static, str>>,
+    demonyms: Vec<&'static str>,
+}
+
+/// A country-name index that can be extended with custom aliases at runtime.
+///
+/// A new registry starts from the built-in dataset. Aliases added with
+/// [`add_alias`](Self::add_alias) are normalized like built-in names, so case, diacritics,
+/// `St.`/`&` spellings, leading articles, and government titles are all handled. Lookups on
+/// the registry follow the same rules as the free functions.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::CountryRegistry;
+///
+/// let mut registry = CountryRegistry::new();
+/// registry.add_alias("NL", "Holland").unwrap();
+/// registry.add_alias("MK", "Macedonia FYR").unwrap();
+///
+/// assert_eq!(registry.name_to_code("holland"), Some("NL"));
+/// assert_eq!(registry.name_to_code("Macedonia, FYR"), Some("MK"));
+/// assert_eq!(registry.code("🇳🇱"), Some("NL"));
+///
+/// assert!(registry.remove_alias("NL", "Holland"));
+/// assert_eq!(registry.name_to_code("Holland"), None);
+/// ```
+#[derive(Clone)]
+pub struct CountryRegistry {
+    entries: Vec<RegistryEntry>,
+    added_aliases: Vec<(usize, String)>,
+    // Whether the built-in endonyms and localized names are recognized, which is not the case
+    // for registries loaded with replacement data.
+    localized_names: bool,
+    positions: HashMap<&'static str, usize>,
+    index: NameIndex,
+    matcher: Matcher,
+}
+
+impl CountryRegistry {
+    /// Returns a registry containing the built-in dataset.
+    pub fn new() -> Self {
+        let entries = COUNTRIES
+            .iter()
+            .map(|country| RegistryEntry {
+                code: country.code(),
+                names: country.names().iter().copied().map(Cow::Borrowed).collect(),
+                demonyms: country.demonyms().to_vec(),
+            })
+            .collect();
+
+        Self::from_entries(entries, COUNTRIES_INDEX.clone(), true)
+    }
+
+    fn from_entries(entries: Vec<RegistryEntry>, index: NameIndex, localized_names: bool) -> Self {
+        let positions = entries
+            .iter()
+            .enumerate()
+            .map(|(position, entry)| (entry.code, position))
+            .collect();
+
+        Self {
+            entries,
+            added_aliases: Vec::new(),
+            localized_names,
+            positions,
+            index,
+            matcher: Matcher::default(),
+        }
+    }
+
+    /// Uses `matcher` for name lookups on this registry instead of the default [`Matcher`].
+    pub fn with_matcher(mut self, matcher: Matcher) -> Self {
+        self.matcher = matcher;
+        self
+    }
+
+    /// Adds an alternative name for a country.
+    ///
+    /// An alias takes precedence over built-in names that normalize to the same text, so it
+    /// can also be used to redirect an existing name to another country.
+    ///
+    /// # Arguments
+    /// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+    /// * `alias` - The name to recognize
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the alias was added
+    /// * `Err(ParseCountryCodeError)` - If the code is not in the registry
+    pub fn add_alias(
+        &mut self,
+        code: impl AsRef<str>,
+        alias: impl Into<String>,
+    ) -> Result<(), ParseCountryCodeError> {
+        let position = self.position(code.as_ref())?;
+        let alias = alias.into();
+        if normalize_text(&alias).is_empty() {
+            return Ok(());
+        }
+
+        self.push_alias(position, alias);
+        Ok(())
+    }
+
+    /// Removes an alternative name from a country.
+    ///
+    /// Names are compared after normalization, so `"st. kitts"` removes `"Saint Kitts"`.
+    /// Built-in aliases and demonyms can be removed as well, but the preferred name cannot.
+    ///
+    /// # Arguments
+    /// * `code` - An ISO 3166-1 alpha-2 country code (case-insensitive) or a [`CountryCode`]
+    /// * `alias` - The name to stop recognizing
+    ///
+    /// # Returns
+    /// * `true` - If at least one alias was removed
+    /// * `false` - If the code is not in the registry or has no such alias
+    pub fn remove_alias(&mut self, code: impl AsRef<str>, alias: &str) -> bool {
+        let Ok(position) = self.position(code.as_ref()) else {
+            return false;
+        };
+
+        let normalized_alias = normalize_text(alias);
+        let is_alias = |name: &str| normalize_text(name) == normalized_alias;
+        let entry = &mut self.entries[position];
+        let name_count = entry.names.len() + entry.demonyms.len() + self.added_aliases.len();
+
+        let mut aliases = entry.names.split_off(1);
+        aliases.retain(|name| !is_alias(name));
+        entry.names.append(&mut aliases);
+        entry.demonyms.retain(|demonym| !is_alias(demonym));
+        self.added_aliases
+            .retain(|(added_position, name)| *added_position != position || !is_alias(name));
+
+        if entry.names.len() + entry.demonyms.len() + self.added_aliases.len() == name_count {
+            return false;
+        }
+
+        self.rebuild_index();
+        true
+    }
+
+    /// Returns the preferred name of a country in this registry.
+    ///
+    /// This is the built-in name unless the registry was loaded with replacement data.
+    pub fn name(&self, code: impl AsRef<str>) -> Option<&str> {
+        self.position(code.as_ref())
+            .ok()
+            .map(|position| self.entries[position].names[0].as_ref())
+    }
+
+    /// Returns the alternative names recognized for a country, excluding the preferred name.
+    ///
+    /// The iterator is empty if the code is not in the registry.
+    pub fn aliases(&self, code: impl AsRef<str>) -> impl Iterator<Item = &str> {
+        self.position(code.as_ref())
+            .ok()
+            .into_iter()
+            .flat_map(move |position| {
+                let added = self
+                    .added_aliases
+                    .iter()
+                    .filter(move |(added_position, _)| *added_position == position)
+                    .map(|(_, alias)| alias.as_str());
+                self.entries[position].names[1..]
+                    .iter()
+                    .map(|name| name.as_ref())
+                    .chain(added)
+            })
+    }
+
+    /// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1
+    /// alpha-2 code.
+    ///
+    /// Behaves like [`code`](crate::code), using this registry's names. Only the countries in
+    /// the registry are resolved, never subdivisions.
+    pub fn code(&self, input: &str) -> Option<&'static str> {
+        self.code_match(input).or_else(|| self.name_to_code(input))
+    }
+
+    /// Resolves a flag emoji, ISO country code, or country-like text to an ISO 3166-1
+    /// alpha-2 code, explaining why the lookup failed.
+    ///
+    /// Behaves like [`try_code`](crate::try_code), using this registry's names.
+    pub fn try_code(&self, input: &str) -> Result<&'static str, LookupError> {
+        match self.code_match(input) {
+            Some(code) => Ok(code),
+            None => self.try_name_to_code(input),
+        }
+    }
+
+    /// Resolves country-like text to an ISO 3166-1 alpha-2 code.
+    ///
+    /// Behaves like [`name_to_code`](crate::name_to_code), using this registry's names.
+    pub fn name_to_code(&self, name: &str) -> Option<&'static str> {
+        self.name_match(name).map(|name_match| name_match.code())
+    }
+
+    /// Resolves country-like text and reports how confident the match is.
+    ///
+    /// Behaves like [`name_match`](crate::name_match), using this registry's names.
+    pub fn name_match(&self, name: &str) -> Option<Match> {
+        self.matcher.name_match_in(&self.index, name)
+    }
+
+    /// Resolves country-like text to an ISO 3166-1 alpha-2 code, explaining why the lookup
+    /// failed.
+    ///
+    /// Behaves like [`try_name_to_code`](crate::try_name_to_code), using this registry's
+    /// names.
+    pub fn try_name_to_code(&self, name: &str) -> Result<&'static str, LookupError> {
+        self.matcher.try_name_to_code_in(&self.index, name)
+    }
+
+    /// Returns the best-scoring country codes for country-like text, highest score first.
+    ///
+    /// Behaves like [`name_candidates`](crate::name_candidates), using this registry's
+    /// names.
+    pub fn name_candidates(&self, input: &str, limit: usize) -> Vec<(&'static str, f32)> {
+        self.matcher.name_candidates_in(&self.index, input, limit)
+    }
+
+    /// Resolves a flag emoji, ISO country code, or country-like text to a [`Country`].
+    ///
+    /// Behaves like [`country`](crate::country), using this registry's names.
+    pub fn country(&self, input: &str) -> Option<&'static Country> {
+        self.code(input).and_then(get_by_code)
+    }
+
+    fn code_match(&self, input: &str) -> Option<&'static str> {
+        flag_to_code(input)
+            .or_else(|| get_by_code_input(input).map(Country::code))
+            .filter(|code| self.positions.contains_key(code))
+    }
+
+    fn position(&self, code: &str) -> Result<usize, ParseCountryCodeError> {
+        let code: CountryCode = code.parse()?;
+        self.positions
+            .get(code.as_str())
+            .copied()
+            .ok_or_else(|| ParseCountryCodeError::new(code.as_str()))
+    }
+
+    fn push_alias(&mut self, position: usize, alias: String) {
+        self.index.insert_alias(position, &alias);
+        self.added_aliases.push((position, alias));
+    }
+
+    // Added aliases are registered after every other name, as they were when added, so that
+    // redirected names keep pointing to their new country.
+    fn rebuild_index(&mut self) {
+        self.index = build_index(&self.entries, self.localized_names);
+        for (position, alias) in &self.added_aliases {
+            self.index.insert_alias(*position, alias);
+        }
+    }
+}
+
+fn build_index(entries: &[RegistryEntry], localized_names: bool) -> NameIndex {
+    let mut index = NameIndex::with_capacity(entries.len());
+    for entry in entries {
+        let names: Vec<&str> = entry.names.iter().map(|name| name.as_ref()).collect();
+        index.insert_country(entry.code, &names);
+    }
+    for entry in entries {
+        for demonym in &entry.demonyms {
+            index.insert_demonym(entry.code, demonym);
+        }
+    }
+    if localized_names {
+        index.insert_localized_names();
+    }
+    index
+}
+
+impl Default for CountryRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for CountryRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountryRegistry")
+            .field("countries", &self.entries.len())
+            .field("matcher", &self.matcher)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,346 @@
+use super::{build_index, CountryRegistry, RegistryEntry};
+use crate::{
+    demonym_match, direct_name_match, lookup_country_name, normalize_text, CountryCode, NameIndex,
+    COUNTRIES_INDEX,
+};
+#[cfg(feature = "json")]
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Read;
+
+/// The file format read by [`CountryRegistry::from_reader`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+    /// Comma-separated values with a `code,name,aliases` header. Multiple aliases are
+    /// separated by `;`.
+    #[cfg(feature = "csv")]
+    Csv,
+    /// A JSON array of `{"code": "..", "name": "..", "aliases": [".."]}` objects.
+    #[cfg(feature = "json")]
+    Json,
+}
+
+/// How records loaded by [`CountryRegistry::from_reader`] are combined with the built-in
+/// dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadMode {
+    /// Keeps the built-in dataset and adds every loaded name as an alias of its code.
+    Extend,
+    /// Uses only the loaded records. Each record's `name` becomes its preferred name, and
+    /// codes without a record are no longer recognized. Built-in aliases, demonyms, native
+    /// names, and localized names are not recognized either.
+    Replace,
+}
+
+/// The error returned when [`CountryRegistry::from_reader`] cannot load a file.
+///
+/// Validation errors carry the 1-based number of the offending record, not counting the
+/// CSV header.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum LoadError {
+    /// The CSV input could not be read or parsed.
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    /// The JSON input could not be read or parsed.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// A record's code is not a known ISO 3166-1 alpha-2 code.
+    InvalidCode {
+        /// The offending record.
+        row: usize,
+        /// The code as written in the file.
+        code: String,
+    },
+    /// A code appears in more than one record.
+    DuplicateCode {
+        /// The offending record.
+        row: usize,
+        /// The repeated code.
+        code: &'static str,
+    },
+    /// A record has no name, which [`LoadMode::Replace`] requires.
+    MissingName {
+        /// The offending record.
+        row: usize,
+        /// The code of the record.
+        code: &'static str,
+    },
+    /// A name or alias is already used by a record for another code or, when extending the
+    /// built-in dataset, already resolves to another code.
+    ConflictingAlias {
+        /// The offending record.
+        row: usize,
+        /// The name as written in the file.
+        alias: String,
+        /// The code of the offending record.
+        code: &'static str,
+        /// The code the name is already used for.
+        existing_code: &'static str,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "csv")]
+            LoadError::Csv(error) => write!(f, "invalid CSV: {error}"),
+            #[cfg(feature = "json")]
+            LoadError::Json(error) => write!(f, "invalid JSON: {error}"),
+            LoadError::InvalidCode { row, code } => {
+                write!(f, "row {row}: unknown country code \"{code}\"")
+            }
+            LoadError::DuplicateCode { row, code } => {
+                write!(f, "row {row}: duplicate record for {code}")
+            }
+            LoadError::MissingName { row, code } => {
+                write!(f, "row {row}: missing name for {code}")
+            }
+            LoadError::ConflictingAlias {
+                row,
+                alias,
+                code,
+                existing_code,
+            } => write!(
+                f,
+                "row {row}: \"{alias}\" for {code} is already used for {existing_code}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "csv")]
+            LoadError::Csv(error) => Some(error),
+            #[cfg(feature = "json")]
+            LoadError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct JsonRecord {
+    code: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+}
+
+struct Record {
+    code: String,
+    names: Vec<String>,
+}
+
+impl CountryRegistry {
+    /// Builds a registry from a CSV or JSON file of country names.
+    ///
+    /// Every record has a `code`, an optional `name`, and optional `aliases`. The whole file
+    /// is validated before the registry is built, and the first invalid record is reported.
+    /// When extending the built-in dataset, a name that already resolves to another country is
+    /// invalid; use [`add_alias`](CountryRegistry::add_alias) to redirect it deliberately.
+    ///
+    /// # Arguments
+    /// * `reader` - The file contents
+    /// * `format` - Whether the contents are CSV or JSON
+    /// * `mode` - Whether to extend or replace the built-in dataset
+    ///
+    /// # Returns
+    /// * `Ok(CountryRegistry)` - The loaded registry
+    /// * `Err(LoadError)` - If the input cannot be parsed or a record is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "csv")]
+    /// # {
+    /// use country_emoji::{CountryRegistry, DataFormat, LoadMode};
+    ///
+    /// let data = "code,name,aliases\n\
+    ///             NL,Holland,Nederland;The Low Countries\n\
+    ///             MK,,Macedonia FYR\n";
+    ///
+    /// let registry =
+    ///     CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Extend)
+    ///         .unwrap();
+    /// assert_eq!(registry.code("Low Countries"), Some("NL"));
+    /// assert_eq!(registry.code("Macedonia FYR"), Some("MK"));
+    /// assert_eq!(registry.code("Germany"), Some("DE"));
+    ///
+    /// let registry =
+    ///     CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Replace);
+    /// assert_eq!(
+    ///     registry.unwrap_err().to_string(),
+    ///     "row 2: missing name for MK"
+    /// );
+    /// # }
+    /// ```
+    pub fn from_reader<R: Read>(
+        reader: R,
+        format: DataFormat,
+        mode: LoadMode,
+    ) -> Result<Self, LoadError> {
+        let records = match format {
+            #[cfg(feature = "csv")]
+            DataFormat::Csv => read_csv(reader)?,
+            #[cfg(feature = "json")]
+            DataFormat::Json => read_json(reader)?,
+        };
+
+        let entries = validate_records(records, mode)?;
+
+        Ok(match mode {
+            LoadMode::Extend => {
+                let mut registry = CountryRegistry::new();
+                for entry in entries {
+                    let position = registry.positions[entry.code];
+                    for name in entry.names {
+                        registry.push_alias(position, name.into_owned());
+                    }
+                }
+                registry
+            }
+            LoadMode::Replace => {
+                let index = build_index(&entries, false);
+                CountryRegistry::from_entries(entries, index, false)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "csv")]
+fn read_csv<R: Read>(reader: R) -> Result<Vec<Record>, LoadError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(reader);
+
+    // Columns are located by header so that `name` and `aliases` can be omitted.
+    let headers = reader.headers().map_err(LoadError::Csv)?.clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(name))
+    };
+    let (code_column, name_column, aliases_column) =
+        (column("code"), column("name"), column("aliases"));
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(LoadError::Csv)?;
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|column| record.get(column))
+                    .unwrap_or_default()
+            };
+
+            Ok(Record {
+                code: field(code_column).to_string(),
+                names: std::iter::once(field(name_column))
+                    .chain(field(aliases_column).split(';'))
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "json")]
+fn read_json<R: Read>(reader: R) -> Result<Vec<Record>, LoadError> {
+    let records: Vec<JsonRecord> = serde_json::from_reader(reader).map_err(LoadError::Json)?;
+    Ok(records
+        .into_iter()
+        .map(|record| Record {
+            code: record.code,
+            names: std::iter::once(record.name).chain(record.aliases).collect(),
+        })
+        .collect())
+}
+
+// The first name of each record is its `name` field, which may be empty. Names added to the
+// built-in dataset must not already resolve to another country.
+fn validate_records(records: Vec<Record>, mode: LoadMode) -> Result<Vec<RegistryEntry>, LoadError> {
+    let existing_index = (mode == LoadMode::Extend).then_some(&*COUNTRIES_INDEX);
+    let mut codes: HashMap<&'static str, usize> = HashMap::new();
+    let mut names: HashMap<String, &'static str> = HashMap::new();
+    let mut entries = Vec::with_capacity(records.len());
+
+    for (position, record) in records.into_iter().enumerate() {
+        let row = position + 1;
+        let code = record
+            .code
+            .parse::<CountryCode>()
+            .map_err(|_| LoadError::InvalidCode {
+                row,
+                code: record.code.clone(),
+            })?
+            .as_str();
+
+        if codes.insert(code, row).is_some() {
+            return Err(LoadError::DuplicateCode { row, code });
+        }
+
+        let has_name = record
+            .names
+            .first()
+            .is_some_and(|name| !normalize_text(name).is_empty());
+        if mode == LoadMode::Replace && !has_name {
+            return Err(LoadError::MissingName { row, code });
+        }
+
+        let mut entry_names = Vec::with_capacity(record.names.len());
+        for name in record.names {
+            let normalized = normalize_text(&name);
+            if normalized.is_empty() {
+                continue;
+            }
+
+            let existing_code = match names.entry(normalized) {
+                Entry::Occupied(existing) if *existing.get() == code => continue,
+                Entry::Occupied(existing) => Some(*existing.get()),
+                Entry::Vacant(vacant) => {
+                    let existing_code = existing_index
+                        .and_then(|index| indexed_code(index, &name, vacant.key()))
+                        .filter(|&existing_code| existing_code != code);
+                    vacant.insert(code);
+                    existing_code
+                }
+            };
+
+            if let Some(existing_code) = existing_code {
+                return Err(LoadError::ConflictingAlias {
+                    row,
+                    alias: name.trim().to_string(),
+                    code,
+                    existing_code,
+                });
+            }
+
+            entry_names.push(Cow::Owned(name.trim().to_string()));
+        }
+
+        entries.push(RegistryEntry {
+            code,
+            names: entry_names,
+            demonyms: Vec::new(),
+        });
+    }
+
+    Ok(entries)
+}
+
+fn indexed_code(index: &NameIndex, name: &str, normalized: &str) -> Option<&'static str> {
+    let name = name.trim();
+    direct_name_match(index, name)
+        .or_else(|| lookup_country_name(index, normalized))
+        .or_else(|| demonym_match(index, name))
+}
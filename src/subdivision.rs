@@ -0,0 +1,165 @@
+//! ISO 3166-2 subdivisions and their flag emoji tag sequences.
+//!
+//! A subdivision flag is a black flag followed by the ISO 3166-2 code written in Unicode tag
+//! characters, such as `gbsct` for Scotland, and a cancel tag.
+//!
+//! England, Scotland, and Wales are always available because they have flags of their own.
+//! The `subdivisions` feature adds the subdivisions of Brazil, Canada, Germany, and the United
+//! States.
+
+use crate::{normalize_text, NameIndex};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+#[cfg(feature = "subdivisions")]
+mod data;
+
+// Each entry lists its ISO 3166-2 name first, followed by common alternatives.
+#[cfg(feature = "subdivisions")]
+const DATASET: &[(&str, &[&str])] = data::SUBDIVISIONS;
+#[cfg(not(feature = "subdivisions"))]
+const DATASET: &[(&str, &[&str])] = &[];
+
+const BLACK_FLAG: char = '\u{1F3F4}';
+const CANCEL_TAG: char = '\u{E007F}';
+const TAG_OFFSET: u32 = 0xE0000;
+
+/// The subdivisions with a recommended (RGI) flag emoji, with the names they are known by.
+const FLAG_SUBDIVISIONS: &[(&str, &[&str])] = &[
+    ("GB-ENG", &["England"]),
+    ("GB-SCT", &["Scotland", "Alba"]),
+    ("GB-WLS", &["Wales", "Cymru"]),
+];
+
+/// Words too generic to identify a subdivision on their own. Country words such as "united" or
+/// "republic" are left out, while directions and words naming a kind of subdivision are in.
+const GENERIC_WORDS: &[&str] = &[
+    "the",
+    "of",
+    "and",
+    "&",
+    "de",
+    "do",
+    "du",
+    "new",
+    "north",
+    "south",
+    "east",
+    "west",
+    "saint",
+    "st",
+    "sao",
+    "state",
+    "district",
+    "distrito",
+    "federal",
+    "territory",
+    "territories",
+    "island",
+    "islands",
+];
+
+static SUBDIVISIONS_BY_CODE: Lazy<HashMap<&'static str, &'static [&'static str]>> =
+    Lazy::new(|| subdivisions().map(|&(code, names)| (code, names)).collect());
+
+pub(crate) static SUBDIVISIONS_INDEX: Lazy<NameIndex> = Lazy::new(|| {
+    let mut index = NameIndex::with_capacity(FLAG_SUBDIVISIONS.len() + DATASET.len())
+        .with_generic_words(GENERIC_WORDS);
+    for &(code, names) in subdivisions() {
+        index.insert_country(code, names);
+    }
+    index
+});
+
+fn subdivisions() -> impl Iterator<Item = &'static (&'static str, &'static [&'static str])> {
+    FLAG_SUBDIVISIONS.iter().chain(DATASET)
+}
+
+/// Returns the code and names of a known subdivision, looked up case-insensitively.
+pub(crate) fn subdivision_by_code(code: &str) -> Option<(&'static str, &'static [&'static str])> {
+    SUBDIVISIONS_BY_CODE
+        .get_key_value(code.trim().to_ascii_uppercase().as_str())
+        .map(|(&code, &names)| (code, names))
+}
+
+/// Returns the ISO 3166-2 code of a subdivision that has a flag emoji.
+pub(crate) fn flag_subdivision_by_code(code: &str) -> Option<&'static str> {
+    let code = code.trim();
+    FLAG_SUBDIVISIONS
+        .iter()
+        .map(|&(subdivision, _)| subdivision)
+        .find(|subdivision| subdivision.eq_ignore_ascii_case(code))
+}
+
+/// Returns the ISO 3166-2 code of a subdivision that has a flag emoji, matched by name after
+/// normalization.
+pub(crate) fn flag_subdivision_by_name(name: &str) -> Option<&'static str> {
+    let normalized = normalize_text(name);
+    FLAG_SUBDIVISIONS
+        .iter()
+        .find(|(_, names)| names.iter().any(|name| normalize_text(name) == normalized))
+        .map(|&(subdivision, _)| subdivision)
+}
+
+/// Returns the preferred name of a subdivision that has a flag emoji.
+pub(crate) fn flag_subdivision_name(code: &str) -> Option<&'static str> {
+    FLAG_SUBDIVISIONS
+        .iter()
+        .find(|&&(subdivision, _)| subdivision == code)
+        .map(|(_, names)| names[0])
+}
+
+/// Returns the ISO 3166-2 code for a subdivision flag emoji.
+pub(crate) fn flag_to_subdivision_code(flag: &str) -> Option<&'static str> {
+    let flag = flag.trim();
+    tag_sequence_prefix(flag)
+        .filter(|&(len, _)| len == flag.len())
+        .map(|(_, subdivision)| subdivision)
+}
+
+/// Parses a subdivision flag at the start of `text`, returning its length in bytes and its
+/// ISO 3166-2 code.
+pub(crate) fn tag_sequence_prefix(text: &str) -> Option<(usize, &'static str)> {
+    let tags = text.strip_prefix(BLACK_FLAG)?;
+    let end = tags.find(|tag: char| tag_to_ascii(tag).is_none())?;
+    if !tags[end..].starts_with(CANCEL_TAG) {
+        return None;
+    }
+
+    let tag: String = tags[..end].chars().filter_map(tag_to_ascii).collect();
+    let subdivision = FLAG_SUBDIVISIONS
+        .iter()
+        .map(|&(subdivision, _)| subdivision)
+        .find(|subdivision| {
+            subdivision
+                .bytes()
+                .filter(|&byte| byte != b'-')
+                .map(|byte| byte.to_ascii_lowercase())
+                .eq(tag.bytes())
+        })?;
+
+    Some((
+        BLACK_FLAG.len_utf8() + end + CANCEL_TAG.len_utf8(),
+        subdivision,
+    ))
+}
+
+/// Builds the flag emoji of a subdivision code such as `"GB-SCT"`.
+pub(crate) fn subdivision_flag_emoji(code: &str) -> String {
+    std::iter::once(BLACK_FLAG)
+        .chain(
+            code.bytes()
+                .filter(u8::is_ascii_alphanumeric)
+                .filter_map(|byte| char::from_u32(TAG_OFFSET + byte.to_ascii_lowercase() as u32)),
+        )
+        .chain(std::iter::once(CANCEL_TAG))
+        .collect()
+}
+
+// Subdivision tags are lowercase letters and digits.
+fn tag_to_ascii(tag: char) -> Option<char> {
+    (tag as u32)
+        .checked_sub(TAG_OFFSET)
+        .and_then(char::from_u32)
+        .filter(|ascii| ascii.is_ascii_lowercase() || ascii.is_ascii_digit())
+}
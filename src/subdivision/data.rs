@@ -0,0 +1,139 @@
+//! ISO 3166-2 subdivisions of Brazil, Canada, Germany, and the United States.
+
+pub(super) const SUBDIVISIONS: &[(&str, &[&str])] = &[
+    ("BR-AC", &["Acre"]),
+    ("BR-AL", &["Alagoas"]),
+    ("BR-AM", &["Amazonas"]),
+    ("BR-AP", &["Amapá"]),
+    ("BR-BA", &["Bahia"]),
+    ("BR-CE", &["Ceará"]),
+    ("BR-DF", &["Distrito Federal", "Federal District"]),
+    ("BR-ES", &["Espírito Santo"]),
+    ("BR-GO", &["Goiás"]),
+    ("BR-MA", &["Maranhão"]),
+    ("BR-MG", &["Minas Gerais"]),
+    ("BR-MS", &["Mato Grosso do Sul"]),
+    ("BR-MT", &["Mato Grosso"]),
+    ("BR-PA", &["Pará"]),
+    ("BR-PB", &["Paraíba"]),
+    ("BR-PE", &["Pernambuco"]),
+    ("BR-PI", &["Piauí"]),
+    ("BR-PR", &["Paraná"]),
+    ("BR-RJ", &["Rio de Janeiro"]),
+    ("BR-RN", &["Rio Grande do Norte"]),
+    ("BR-RO", &["Rondônia"]),
+    ("BR-RR", &["Roraima"]),
+    ("BR-RS", &["Rio Grande do Sul"]),
+    ("BR-SC", &["Santa Catarina"]),
+    ("BR-SE", &["Sergipe"]),
+    ("BR-SP", &["São Paulo"]),
+    ("BR-TO", &["Tocantins"]),
+    ("CA-AB", &["Alberta"]),
+    ("CA-BC", &["British Columbia"]),
+    ("CA-MB", &["Manitoba"]),
+    ("CA-NB", &["New Brunswick"]),
+    ("CA-NL", &["Newfoundland and Labrador"]),
+    ("CA-NS", &["Nova Scotia"]),
+    (
+        "CA-NT",
+        &["Northwest Territories", "Territoires du Nord-Ouest"],
+    ),
+    ("CA-NU", &["Nunavut"]),
+    ("CA-ON", &["Ontario"]),
+    ("CA-PE", &["Prince Edward Island", "PEI"]),
+    ("CA-QC", &["Quebec", "Québec"]),
+    ("CA-SK", &["Saskatchewan"]),
+    ("CA-YT", &["Yukon", "Yukon Territory"]),
+    ("DE-BB", &["Brandenburg"]),
+    ("DE-BE", &["Berlin"]),
+    ("DE-BW", &["Baden-Württemberg"]),
+    ("DE-BY", &["Bayern", "Bavaria"]),
+    ("DE-HB", &["Bremen"]),
+    ("DE-HE", &["Hessen", "Hesse"]),
+    ("DE-HH", &["Hamburg"]),
+    (
+        "DE-MV",
+        &["Mecklenburg-Vorpommern", "Mecklenburg-Western Pomerania"],
+    ),
+    ("DE-NI", &["Niedersachsen", "Lower Saxony"]),
+    ("DE-NW", &["Nordrhein-Westfalen", "North Rhine-Westphalia"]),
+    ("DE-RP", &["Rheinland-Pfalz", "Rhineland-Palatinate"]),
+    ("DE-SH", &["Schleswig-Holstein"]),
+    ("DE-SL", &["Saarland"]),
+    ("DE-SN", &["Sachsen", "Saxony"]),
+    ("DE-ST", &["Sachsen-Anhalt", "Saxony-Anhalt"]),
+    ("DE-TH", &["Thüringen", "Thuringia"]),
+    ("US-AK", &["Alaska"]),
+    ("US-AL", &["Alabama"]),
+    ("US-AR", &["Arkansas"]),
+    ("US-AS", &["American Samoa"]),
+    ("US-AZ", &["Arizona"]),
+    ("US-CA", &["California"]),
+    ("US-CO", &["Colorado"]),
+    ("US-CT", &["Connecticut"]),
+    (
+        "US-DC",
+        &["District of Columbia", "Washington, D.C.", "Washington DC"],
+    ),
+    ("US-DE", &["Delaware"]),
+    ("US-FL", &["Florida"]),
+    ("US-GA", &["Georgia"]),
+    ("US-GU", &["Guam"]),
+    ("US-HI", &["Hawaii"]),
+    ("US-IA", &["Iowa"]),
+    ("US-ID", &["Idaho"]),
+    ("US-IL", &["Illinois"]),
+    ("US-IN", &["Indiana"]),
+    ("US-KS", &["Kansas"]),
+    ("US-KY", &["Kentucky"]),
+    ("US-LA", &["Louisiana"]),
+    ("US-MA", &["Massachusetts"]),
+    ("US-MD", &["Maryland"]),
+    ("US-ME", &["Maine"]),
+    ("US-MI", &["Michigan"]),
+    ("US-MN", &["Minnesota"]),
+    ("US-MO", &["Missouri"]),
+    ("US-MP", &["Northern Mariana Islands"]),
+    ("US-MS", &["Mississippi"]),
+    ("US-MT", &["Montana"]),
+    ("US-NC", &["North Carolina"]),
+    ("US-ND", &["North Dakota"]),
+    ("US-NE", &["Nebraska"]),
+    ("US-NH", &["New Hampshire"]),
+    ("US-NJ", &["New Jersey"]),
+    ("US-NM", &["New Mexico"]),
+    ("US-NV", &["Nevada"]),
+    ("US-NY", &["New York"]),
+    ("US-OH", &["Ohio"]),
+    ("US-OK", &["Oklahoma"]),
+    ("US-OR", &["Oregon"]),
+    ("US-PA", &["Pennsylvania"]),
+    ("US-PR", &["Puerto Rico"]),
+    ("US-RI", &["Rhode Island"]),
+    ("US-SC", &["South Carolina"]),
+    ("US-SD", &["South Dakota"]),
+    ("US-TN", &["Tennessee"]),
+    ("US-TX", &["Texas"]),
+    (
+        "US-UM",
+        &[
+            "United States Minor Outlying Islands",
+            "U.S. Minor Outlying Islands",
+        ],
+    ),
+    ("US-UT", &["Utah"]),
+    ("US-VA", &["Virginia"]),
+    (
+        "US-VI",
+        &[
+            "Virgin Islands, U.S.",
+            "U.S. Virgin Islands",
+            "US Virgin Islands",
+        ],
+    ),
+    ("US-VT", &["Vermont"]),
+    ("US-WA", &["Washington"]),
+    ("US-WI", &["Wisconsin"]),
+    ("US-WV", &["West Virginia"]),
+    ("US-WY", &["Wyoming"]),
+];
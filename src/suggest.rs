@@ -0,0 +1,182 @@
+use crate::countries::COUNTRIES;
+use crate::locale::localized_name;
+use crate::{normalize_text, Locale};
+use once_cell::sync::Lazy;
+
+// Inner words that never start a suggestion on their own.
+const SKIPPED_INNER_WORDS: &[&str] = &["and", "of", "the"];
+
+/// A searchable form of a name. Entries are sorted by `key` so that every key starting with
+/// a prefix forms one contiguous range.
+struct SuggestionEntry {
+    key: Box<str>,
+    // The lowercased name, when it differs from the key, for preferring suggestions whose
+    // spelling matches the input's diacritics.
+    lowercase: Option<Box<str>>,
+    rank: Rank,
+    code: &'static str,
+}
+
+/// Orders suggestions for a matching key: official codes first, then preferred names before
+/// alternative names before words inside them, then endonyms and translations before words
+/// inside them, then shorter names.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Rank {
+    unofficial: bool,
+    kind: u8,
+    len: usize,
+}
+
+const PREFERRED_NAME: u8 = 0;
+const ALTERNATIVE_NAME: u8 = 1;
+const INNER_WORD: u8 = 2;
+const OTHER_LANGUAGE_NAME: u8 = 3;
+const OTHER_LANGUAGE_INNER_WORD: u8 = 4;
+
+// A candidate suggestion, compared by spelling first and rank second.
+type Candidate = (bool, Rank, &'static str);
+
+static SUGGESTION_ENTRIES: Lazy<Vec<SuggestionEntry>> = Lazy::new(|| {
+    let mut entries = Vec::new();
+    for country in COUNTRIES.iter() {
+        let code = country.code();
+        let unofficial = !country.status().is_official();
+        let other_language_names = country.endonyms().iter().copied().chain(
+            Locale::ALL
+                .iter()
+                .filter_map(move |&locale| localized_name(code, locale)),
+        );
+
+        push_entries(
+            &mut entries,
+            country.name(),
+            code,
+            unofficial,
+            PREFERRED_NAME,
+        );
+        for name in &country.names()[1..] {
+            push_entries(&mut entries, name, code, unofficial, ALTERNATIVE_NAME);
+        }
+        for name in other_language_names {
+            push_entries(&mut entries, name, code, unofficial, OTHER_LANGUAGE_NAME);
+        }
+    }
+
+    entries.sort_by(|left, right| {
+        (&left.key, left.code, left.rank).cmp(&(&right.key, right.code, right.rank))
+    });
+    entries.dedup_by(|next, previous| next.key == previous.key && next.code == previous.code);
+    entries
+});
+
+fn push_entries(
+    entries: &mut Vec<SuggestionEntry>,
+    name: &str,
+    code: &'static str,
+    unofficial: bool,
+    kind: u8,
+) {
+    let key = normalize_text(name);
+    if key.is_empty() {
+        return;
+    }
+
+    let lowercase = name.to_lowercase();
+    let len = key.len();
+    for (start, word) in word_starts(&key) {
+        if start > 0 && SKIPPED_INNER_WORDS.contains(&word) {
+            continue;
+        }
+
+        let inner = start > 0;
+        entries.push(SuggestionEntry {
+            key: key[start..].into(),
+            lowercase: (!inner && lowercase != key).then(|| lowercase.as_str().into()),
+            rank: Rank {
+                kind: match kind {
+                    OTHER_LANGUAGE_NAME if inner => OTHER_LANGUAGE_INNER_WORD,
+                    _ if inner => INNER_WORD,
+                    _ => kind,
+                },
+                unofficial,
+                len,
+            },
+            code,
+        });
+    }
+}
+
+// Separators such as the dash in "Congo - Kinshasa" are not words and never start a
+// suggestion.
+fn word_starts(key: &str) -> impl Iterator<Item = (usize, &str)> {
+    key.split(' ')
+        .scan(0, |start, word| {
+            let word_start = *start;
+            *start += word.len() + 1;
+            Some((word_start, word))
+        })
+        .filter(|(_, word)| word.chars().any(char::is_alphanumeric))
+}
+
+pub(crate) fn suggest(prefix: &str, limit: usize) -> Vec<&'static str> {
+    let normalized = normalize_text(prefix);
+    if !normalized.chars().any(char::is_alphabetic) || limit == 0 {
+        return Vec::new();
+    }
+
+    // Input without diacritics matches every spelling equally well.
+    let lowercase = prefix.trim().to_lowercase();
+    let spelling = (lowercase != normalized).then_some(lowercase.as_str());
+    let mut best: Vec<Candidate> = Vec::with_capacity(limit.min(COUNTRIES.len()));
+    collect_matches(&mut best, limit, &normalized, spelling);
+
+    // A trailing "st" may still become "St. Vincent", which normalizes to "saint".
+    if let Some(stem) = normalized
+        .strip_suffix("st.")
+        .or_else(|| normalized.strip_suffix("st"))
+        .filter(|stem| stem.is_empty() || stem.ends_with(' '))
+    {
+        collect_matches(&mut best, limit, &format!("{stem}saint"), spelling);
+    }
+
+    best.into_iter().map(|(_, _, code)| code).collect()
+}
+
+// Keeps the best `limit` candidates in `best`, sorted and with one candidate per code.
+// `spelling` is the lowercased input when normalization changed it, as for "cô".
+fn collect_matches(
+    best: &mut Vec<Candidate>,
+    limit: usize,
+    normalized: &str,
+    spelling: Option<&str>,
+) {
+    let entries = SUGGESTION_ENTRIES.as_slice();
+    let start = entries.partition_point(|entry| entry.key.as_ref() < normalized);
+
+    for entry in entries[start..]
+        .iter()
+        .take_while(|entry| entry.key.starts_with(normalized))
+    {
+        let same_spelling = spelling.is_none_or(|spelling| {
+            entry
+                .lowercase
+                .as_ref()
+                .is_some_and(|name| name.starts_with(spelling))
+        });
+        let candidate = (!same_spelling, entry.rank, entry.code);
+
+        match best.iter().position(|&(_, _, code)| code == entry.code) {
+            Some(position) if candidate < best[position] => {
+                best.remove(position);
+            }
+            Some(_) => continue,
+            None if best.len() < limit => {}
+            None if best.last().is_some_and(|last| candidate < *last) => {
+                best.pop();
+            }
+            None => continue,
+        }
+        let position = best.partition_point(|kept| *kept < candidate);
+        best.insert(position, candidate);
+    }
+}
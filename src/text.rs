@@ -0,0 +1,368 @@
+use crate::countries::COUNTRIES_BY_CODE_INDEX;
+use crate::{
+    code_to_flag, code_to_name, direct_name_match, government_pattern_variants,
+    lookup_country_name, normalize_text, regional_indicator_index, subdivision, subdivision_name,
+    COUNTRIES_INDEX,
+};
+use std::ops::Range;
+
+// The longest code token, as in "GB-SCT".
+const MAX_TOKEN_LEN: usize = 6;
+// More words than any known name, the longest being "Congo, The Democratic Republic of the".
+const MAX_NAME_WORDS: usize = 8;
+// Words that commonly open longer place names, as in "New Jersey" or "North Dakota".
+const NAME_OPENERS: &[&str] = &[
+    "new", "north", "south", "east", "west", "northern", "southern", "eastern", "western", "upper",
+    "lower", "great", "saint", "st", "san", "santa", "fort", "port", "mount", "cape",
+];
+
+/// Finds every flag emoji in arbitrary text.
+///
+/// Country flags are pairs of regional indicator symbols. Consecutive indicators are paired
+/// from left to right, as emoji renderers do, so `"🇺🇸🇨🇦"` yields `US` and `CA`, never the
+/// `SC` formed by the middle two indicators. Pairs that are not a known code and lone
+/// indicators are skipped. Subdivision flags such as `"🏴󠁧󠁢󠁳󠁣󠁴󠁿"` yield their ISO 3166-2 code.
+///
+/// # Arguments
+/// * `text` - Any text, such as a chat message or a social media bio
+///
+/// # Returns
+/// An iterator of the byte range of each flag in `text` and its code, in order of appearance
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::find_flags;
+///
+/// let text = "Visiting 🇺🇸🇨🇦 then 🏴󠁧󠁢󠁳󠁣󠁴󠁿!";
+/// let flags: Vec<_> = find_flags(text).collect();
+///
+/// assert_eq!(flags.len(), 3);
+/// assert_eq!(flags[0], (9..17, "US"));
+/// assert_eq!(flags[1], (17..25, "CA"));
+/// assert_eq!(flags[2].1, "GB-SCT");
+/// assert_eq!(&text[flags[1].0.clone()], "🇨🇦");
+/// ```
+pub fn find_flags(text: &str) -> impl Iterator<Item = (Range<usize>, &'static str)> + '_ {
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        while let Some(character) = text[position..].chars().next() {
+            let start = position;
+            if let Some((len, code)) = subdivision::tag_sequence_prefix(&text[start..]) {
+                position += len;
+                return Some((start..position, code));
+            }
+
+            position += character.len_utf8();
+            let Some(first) = regional_indicator_index(character) else {
+                continue;
+            };
+            let Some(second) = text[position..].chars().next() else {
+                continue;
+            };
+            let Some(second_index) = regional_indicator_index(second) else {
+                continue;
+            };
+
+            // The second indicator belongs to this pair even if the pair is not a known code.
+            position += second.len_utf8();
+            if let Some(country) = COUNTRIES_BY_CODE_INDEX[first * 26 + second_index] {
+                return Some((start..position, country.code()));
+            }
+        }
+        None
+    })
+}
+
+/// Replaces every flag emoji in `text` with the output of `replace`.
+///
+/// Flags are found like [`find_flags`] finds them, and `replace` receives each flag's code.
+///
+/// # Arguments
+/// * `text` - Any text
+/// * `replace` - Returns the replacement for a flag, given its code
+///
+/// # Returns
+/// The text with every recognized flag replaced
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::replace_flags;
+///
+/// assert_eq!(
+///     replace_flags("Going to 🇯🇵🇰🇷", |code| format!("<{code}>")),
+///     "Going to <JP><KR>"
+/// );
+/// assert_eq!(replace_flags("🇩🇪 wins", |_| ""), " wins");
+/// ```
+pub fn replace_flags<F, S>(text: &str, mut replace: F) -> String
+where
+    F: FnMut(&'static str) -> S,
+    S: AsRef<str>,
+{
+    let mut result = String::with_capacity(text.len());
+    let mut copied = 0;
+    for (range, code) in find_flags(text) {
+        result.push_str(&text[copied..range.start]);
+        result.push_str(replace(code).as_ref());
+        copied = range.end;
+    }
+    result.push_str(&text[copied..]);
+    result
+}
+
+/// Replaces every flag emoji in `text` with the bracketed name of its country, for
+/// accessibility and plain-text exports.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::flags_to_names;
+///
+/// assert_eq!(
+///     flags_to_names("Going to 🇯🇵 next week"),
+///     "Going to [Japan] next week"
+/// );
+/// assert_eq!(flags_to_names("🏴󠁧󠁢󠁷󠁬󠁳󠁿 v 🇫🇷"), "[Wales] v [France]");
+/// ```
+pub fn flags_to_names(text: &str) -> String {
+    replace_flags(text, |code| {
+        let name = code_to_name(code)
+            .or_else(|| subdivision_name(code))
+            .unwrap_or(code);
+        format!("[{name}]")
+    })
+}
+
+/// Replaces code tokens such as `":JP:"` or `"[JP]"` in `text` with flag emojis.
+///
+/// A token is an uppercase ISO 3166-1 alpha-2 code, or the ISO 3166-2 code of England,
+/// Scotland, or Wales, between colons or square brackets. A token must stand on its own, with
+/// neither a letter, a digit, nor another closing delimiter right before or after it, so that
+/// times such as `"10:30:DE:"` are not read as tokens. Adjacent tokens such as `":US::CA:"`
+/// stand on their own together. Tokens with unknown codes are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::codes_to_flags;
+///
+/// assert_eq!(codes_to_flags("Next stop :JP: then [KR]"), "Next stop 🇯🇵 then 🇰🇷");
+/// assert_eq!(codes_to_flags("[GB-SCT]"), "🏴󠁧󠁢󠁳󠁣󠁴󠁿");
+/// assert_eq!(codes_to_flags("[US][CA]"), "🇺🇸🇨🇦");
+/// assert_eq!(codes_to_flags("[ZZ] at 10:30:00"), "[ZZ] at 10:30:00");
+/// assert_eq!(codes_to_flags("10:30:DE:"), "10:30:DE:");
+/// ```
+pub fn codes_to_flags(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    // Where the last replaced token ended, so that adjacent tokens such as "[US][CA]" stand on
+    // their own as well.
+    let mut replaced_end = None;
+    while let Some(start) = rest.find([':', '[']) {
+        let offset = text.len() - rest.len() + start;
+        let replacement = code_token(&rest[start..]).filter(|&(len, close, _)| {
+            let is_boundary = |neighbor: Option<char>| {
+                neighbor.is_none_or(|neighbor| {
+                    !neighbor.is_alphanumeric() && neighbor != char::from(close)
+                })
+            };
+            let following = &rest[start + len..];
+            (replaced_end == Some(offset) || is_boundary(text[..offset].chars().next_back()))
+                && (is_boundary(following.chars().next()) || code_token(following).is_some())
+        });
+
+        match replacement {
+            Some((len, _, flag)) => {
+                result.push_str(&rest[..start]);
+                result.push_str(&flag);
+                rest = &rest[start + len..];
+                replaced_end = Some(offset + len);
+            }
+            None => {
+                result.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+// Parses a code token at the start of `text`, returning its length in bytes, its closing
+// delimiter, and its flag.
+fn code_token(text: &str) -> Option<(usize, u8, String)> {
+    let close = match text.bytes().next()? {
+        b':' => b':',
+        b'[' => b']',
+        _ => return None,
+    };
+    let after = &text[1..];
+    let end = after
+        .bytes()
+        .take(MAX_TOKEN_LEN + 1)
+        .position(|byte| byte == close)?;
+    code_token_flag(&after[..end]).map(|flag| (end + 2, close, flag))
+}
+
+fn code_token_flag(token: &str) -> Option<String> {
+    if token.len() == 2 && token.bytes().all(|byte| byte.is_ascii_uppercase()) {
+        return code_to_flag(token);
+    }
+
+    subdivision::flag_subdivision_by_code(token)
+        .filter(|&subdivision| subdivision == token)
+        .map(subdivision::subdivision_flag_emoji)
+}
+
+/// Finds every country name mentioned in arbitrary text.
+///
+/// The text is split into words, and runs of consecutive words are looked up among the known
+/// names, aliases, and their normalized forms, so `"St. Lucia"` and `"Cote d'Ivoire"` are
+/// found as well. Comma-reversed names such as `"Virgin Islands, British"` and possessives such
+/// as `"Brazil's"` are found too. The longest name starting at a word wins, so `"South Sudan"`
+/// is never reported as `"Sudan"`. To avoid reading words such as "us" or "chad" as
+/// countries, a mention must start with a word that is not lowercase, and must not continue a
+/// longer capitalized name, as "Jersey" does in "New Jersey". Fuzzy matching is not attempted.
+///
+/// # Arguments
+/// * `text` - Any text, such as a sentence
+///
+/// # Returns
+/// An iterator of the byte range of each mention in `text` and its ISO 3166-1 alpha-2 code,
+/// in order of appearance
+///
+/// # Examples
+///
+/// ```
+/// use country_emoji::find_countries;
+///
+/// let text = "Flights from Brazil to the United States via St. Lucia";
+/// let mentions: Vec<_> = find_countries(text).collect();
+///
+/// assert_eq!(mentions[0], (13..19, "BR"));
+/// assert_eq!(&text[mentions[1].0.clone()], "United States");
+/// assert_eq!(mentions[1].1, "US");
+/// assert_eq!(&text[mentions[2].0.clone()], "St. Lucia");
+/// assert_eq!(mentions[2].1, "LC");
+///
+/// let codes: Vec<_> = find_countries("South Sudan and Sudan").map(|(_, code)| code).collect();
+/// assert_eq!(codes, ["SS", "SD"]);
+/// ```
+pub fn find_countries(text: &str) -> impl Iterator<Item = (Range<usize>, &'static str)> + '_ {
+    let words: Vec<Range<usize>> = word_ranges(text).collect();
+    let mut position = 0;
+    let mut mention_end = 0;
+    std::iter::from_fn(move || {
+        while position < words.len() {
+            let first = position;
+            position += 1;
+            if !is_capitalized(&text[words[first].clone()])
+                || first > 0
+                    && words[first - 1].start >= mention_end
+                    && continues_name(text, &words[first - 1], &words[first])
+            {
+                continue;
+            }
+
+            let longest = words.len().min(first + MAX_NAME_WORDS);
+            for last in (first..longest).rev() {
+                let range = words[first].start..words[last].end;
+                let mention = &text[range.clone()];
+                let Some(code) = mentioned_code(mention) else {
+                    continue;
+                };
+                // A reversed name must end at its last part, unlike "Sudan, South" in "Sudan,
+                // South Africa".
+                if mention.contains(',')
+                    && words
+                        .get(last + 1)
+                        .is_some_and(|next| continues_name(text, &words[last], next))
+                {
+                    continue;
+                }
+
+                position = last + 1;
+                mention_end = range.end;
+                return Some((range, code));
+            }
+        }
+        None
+    })
+}
+
+fn mentioned_code(mention: &str) -> Option<&'static str> {
+    let normalized = normalize_text(mention);
+    direct_name_match(&COUNTRIES_INDEX, mention)
+        .or_else(|| lookup_country_name(&COUNTRIES_INDEX, &normalized))
+        .or_else(|| {
+            government_pattern_variants(&normalized, true, false)
+                .iter()
+                .find_map(|variant| lookup_country_name(&COUNTRIES_INDEX, variant))
+        })
+}
+
+fn is_capitalized(word: &str) -> bool {
+    !word.chars().next().is_some_and(char::is_lowercase)
+}
+
+// Whether two capitalized words with only spaces between them belong to the same name. A word
+// that opens a sentence is capitalized anyway, so it only counts when it commonly opens names,
+// as "New" or "South" do.
+fn continues_name(text: &str, previous: &Range<usize>, word: &Range<usize>) -> bool {
+    let starts_uppercase = |word: &str| word.chars().next().is_some_and(char::is_uppercase);
+    let previous_word = &text[previous.clone()];
+    if !starts_uppercase(previous_word)
+        || !starts_uppercase(&text[word.clone()])
+        || !text[previous.end..word.start]
+            .chars()
+            .all(char::is_whitespace)
+    {
+        return false;
+    }
+
+    let opens_sentence = text[..previous.start]
+        .trim_end()
+        .chars()
+        .next_back()
+        .is_none_or(|character| matches!(character, '.' | '!' | '?'));
+    !opens_sentence || NAME_OPENERS.contains(&normalize_text(previous_word).as_str())
+}
+
+// Words are runs of letters and digits, joined by apostrophes or hyphens as in "d'Ivoire" or
+// "Guinea-Bissau". A possessive "'s" is left out, so "Brazil's" is the word "Brazil".
+fn word_ranges(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut characters = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        let (start, _) = characters.find(|(_, character)| character.is_alphanumeric())?;
+        let mut end = text.len();
+        while let Some((index, character)) = characters.next() {
+            let is_apostrophe = matches!(character, '\'' | '’');
+            if is_apostrophe && is_possessive_suffix(&text[index + character.len_utf8()..]) {
+                end = index;
+                break;
+            }
+            let joins_next = (is_apostrophe || character == '-')
+                && characters
+                    .peek()
+                    .is_some_and(|(_, next)| next.is_alphanumeric());
+            if !character.is_alphanumeric() && !joins_next {
+                end = index;
+                break;
+            }
+        }
+        Some(start..end)
+    })
+}
+
+// Whether the text after an apostrophe is the "s" of a possessive, ending the word.
+fn is_possessive_suffix(rest: &str) -> bool {
+    let mut characters = rest.chars();
+    characters
+        .next()
+        .is_some_and(|character| character == 's' || character == 'S')
+        && characters
+            .next()
+            .is_none_or(|character| !character.is_alphanumeric())
+}
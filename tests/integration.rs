@@ -1,6 +1,13 @@
 use country_emoji::{
-    code, code_to_flag, code_to_name, flag, flag_to_code, is_code, is_country_flag, name,
-    name_to_code,
+    all_countries, alpha2_to_alpha3, alpha3_to_alpha2, code, code_status, code_strict,
+    code_to_country, code_to_demonym, code_to_endonyms, code_to_flag, code_to_name,
+    code_to_name_in, code_to_numeric, codes_to_flags, country, demonym_to_code, find_countries,
+    find_flags, flag, flag_to_code, flag_to_country, flag_to_subdivision, flags_to_names, is_code,
+    is_code_strict, is_country_flag, name, name_candidates, name_match, name_to_code,
+    name_to_code_strict, name_to_country, numeric_to_code, replace_flags, sorted_by_name,
+    subdivision_name, subdivision_name_to_code, subdivision_to_country, subdivision_to_flag,
+    suggest, try_code, try_name_to_code, CodeStatus, Country, CountryCode, CountryList,
+    CountryOrder, CountryRegistry, Locale, LookupError, MatchKind, Matcher,
 };
 
 #[test]
@@ -71,8 +78,8 @@ fn test_code_operations() {
     assert_eq!(code("Atlantis"), None);
     assert_eq!(code(""), None);
 
-    // Test ambiguous names - Congo actually matches CD (Democratic Republic of the Congo)
-    assert_eq!(code("Congo"), Some("CD")); // Matches "Congo" exactly
+    // Test ambiguous names - Congo is shared by CD and CG
+    assert_eq!(code("Congo"), None);
 }
 
 #[test]
@@ -323,10 +330,20 @@ fn test_case_insensitivity_edge_cases() {
 fn test_ambiguous_names() {
     // Exact names should win over broader fuzzy candidates.
     assert_eq!(code("Guinea"), Some("GN"));
-    assert_eq!(code("Congo"), Some("CD"));
+    assert_eq!(code("Democratic Congo"), Some("CD"));
+    assert_eq!(code("British Virgin Islands"), Some("VG"));
 
-    // Truly ambiguous names should return None.
+    // Truly ambiguous names should return None, as their checked lookups report.
     assert_eq!(code("Korea"), None);
+    for name in ["Congo", "Congo Republic", "Virgin Islands"] {
+        assert_eq!(code(name), None, "{name}");
+        assert_eq!(name_to_code(name), None, "{name}");
+        assert_eq!(flag(name), None, "{name}");
+        assert!(
+            matches!(try_code(name), Err(LookupError::Ambiguous { .. })),
+            "{name}"
+        );
+    }
 }
 
 #[test]
@@ -503,3 +520,1446 @@ fn test_ascii_normalization_lookups() {
                                     // "Principe" works because it's an explicit country/territory name, even if generic-sounding
     assert_eq!(code("Principe"), Some("ST"));
 }
+
+#[test]
+fn test_country_lookups() {
+    let germany = country("Germany").unwrap();
+    assert_eq!(germany.code(), "DE");
+    assert_eq!(germany.name(), "Germany");
+    assert!(germany.aliases().is_empty());
+    assert_eq!(germany.demonyms(), ["German"]);
+    assert_eq!(germany.flag(), "🇩🇪");
+
+    // All lookup paths resolve to the same static entry.
+    assert_eq!(country("🇩🇪"), Some(germany));
+    assert_eq!(code_to_country("de"), Some(germany));
+    assert_eq!(flag_to_country("🇩🇪"), Some(germany));
+    assert_eq!(
+        name_to_country("Federal Republic of Germany"),
+        Some(germany)
+    );
+
+    // Country values agree with the string-based APIs.
+    let uk = country("UK").unwrap();
+    assert_eq!(Some(uk.code()), code("UK"));
+    assert_eq!(Some(uk.name()), name("GB"));
+    assert_eq!(Some(uk.flag()), flag("GB"));
+
+    // Invalid and ambiguous inputs.
+    assert_eq!(country("Atlantis"), None);
+    assert_eq!(country("Korea"), None);
+    assert_eq!(code_to_country("ZZ"), None);
+    assert_eq!(flag_to_country("🏳️"), None);
+    assert_eq!(name_to_country(""), None);
+}
+
+#[test]
+fn test_alpha3_codes() {
+    // Conversions between alpha-2 and alpha-3 codes.
+    assert_eq!(alpha2_to_alpha3("DE"), Some("DEU"));
+    assert_eq!(alpha2_to_alpha3("gb"), Some("GBR"));
+    assert_eq!(alpha2_to_alpha3("XK"), Some("XKX"));
+    assert_eq!(alpha3_to_alpha2("DEU"), Some("DE"));
+    assert_eq!(alpha3_to_alpha2("usa"), Some("US"));
+    assert_eq!(alpha3_to_alpha2(" BRA "), Some("BR"));
+
+    // Entries without an alpha-3 code.
+    assert_eq!(alpha2_to_alpha3("EU"), None);
+    assert_eq!(
+        code_to_country("EU").and_then(|country| country.alpha3()),
+        None
+    );
+
+    // Invalid inputs.
+    assert_eq!(alpha2_to_alpha3("ZZ"), None);
+    assert_eq!(alpha3_to_alpha2("ZZZ"), None);
+    assert_eq!(alpha3_to_alpha2("DE"), None);
+    assert_eq!(alpha3_to_alpha2("DEUT"), None);
+    assert_eq!(alpha3_to_alpha2("D3U"), None);
+
+    // Every alpha-3 code round-trips.
+    for alpha2 in ["AF", "BR", "CN", "FR", "JP", "NG", "SS", "ZW"] {
+        let alpha3 = alpha2_to_alpha3(alpha2).unwrap();
+        assert_eq!(alpha3_to_alpha2(alpha3), Some(alpha2));
+    }
+}
+
+#[test]
+fn test_iso_codes_in_general_lookups() {
+    // code() accepts alpha-2 and alpha-3 codes.
+    assert_eq!(code("DEU"), Some("DE"));
+    assert_eq!(code("USA"), Some("US"));
+    assert_eq!(code("DE"), Some("DE"));
+    assert_eq!(code(" AND "), Some("AD"));
+
+    // Lowercase input is matched as a name, never as a code.
+    assert_eq!(code("de"), None);
+    assert_eq!(code("St"), None);
+    assert_eq!(code("can"), None);
+    assert_eq!(code("and"), None);
+    assert_eq!(try_code("fra"), Err(LookupError::NotFound));
+
+    // flag() and name() accept alpha-3 codes.
+    assert_eq!(flag("DEU"), Some("🇩🇪".to_string()));
+    assert_eq!(flag("JPN"), Some("🇯🇵".to_string()));
+
+    // flag() reads lowercase letter codes as names like code() does, except alpha-2 codes.
+    assert_eq!(flag("us"), Some("🇺🇸".to_string()));
+    for input in ["and", "can", "jpn"] {
+        assert_eq!(flag(input), None, "{input:?}");
+        assert_eq!(flag(input), code(input).and_then(flag), "{input:?}");
+    }
+    assert_eq!(name("USA"), Some("United States"));
+    assert_eq!(name("BRA"), Some("Brazil"));
+
+    // name() reads mixed and lowercase alpha-3 codes the same way.
+    assert_eq!(name("us"), Some("United States"));
+    for input in ["can", "Per", "Cub", "jpn"] {
+        assert_eq!(name(input), None, "{input:?}");
+    }
+
+    // Names still take effect when the input is not a code.
+    assert_eq!(code("UK"), Some("GB"));
+    assert_eq!(country("CAN").map(|country| country.name()), Some("Canada"));
+
+    // Invalid codes.
+    assert_eq!(code("ZZZ"), None);
+    assert_eq!(flag("ZZZ"), None);
+    assert_eq!(name("ZZZ"), None);
+}
+
+#[test]
+fn test_numeric_codes() {
+    // Conversions between alpha-2 and numeric codes.
+    assert_eq!(code_to_numeric("BR"), Some("076"));
+    assert_eq!(code_to_numeric("af"), Some("004"));
+    assert_eq!(code_to_numeric("US"), Some("840"));
+    assert_eq!(numeric_to_code("076"), Some("BR"));
+    assert_eq!(numeric_to_code("004"), Some("AF"));
+    assert_eq!(numeric_to_code(" 826 "), Some("GB"));
+
+    // Entries without a numeric code.
+    assert_eq!(code_to_numeric("EU"), None);
+    assert_eq!(code_to_numeric("XK"), None);
+
+    // Only zero-padded three-digit codes are accepted.
+    assert_eq!(numeric_to_code("76"), None);
+    assert_eq!(numeric_to_code("0076"), None);
+    assert_eq!(numeric_to_code("07A"), None);
+    assert_eq!(numeric_to_code("000"), None);
+    assert_eq!(numeric_to_code("999"), None);
+
+    // code(), flag() and name() accept numeric codes.
+    assert_eq!(code("076"), Some("BR"));
+    assert_eq!(code("276"), Some("DE"));
+    assert_eq!(flag("392"), Some("🇯🇵".to_string()));
+    assert_eq!(name("124"), Some("Canada"));
+    assert_eq!(code("999"), None);
+    assert_eq!(flag("76"), None);
+}
+
+#[test]
+fn test_country_code_enum() {
+    // Parsing is case-insensitive and ignores surrounding whitespace.
+    assert_eq!("DE".parse::<CountryCode>(), Ok(CountryCode::DE));
+    assert_eq!("de".parse::<CountryCode>(), Ok(CountryCode::DE));
+    assert_eq!(CountryCode::try_from(" gb "), Ok(CountryCode::GB));
+    assert!("ZZ".parse::<CountryCode>().is_err());
+    assert!("DEU".parse::<CountryCode>().is_err());
+    assert!(CountryCode::try_from("").is_err());
+    assert_eq!(
+        "ZZ".parse::<CountryCode>().unwrap_err().to_string(),
+        "unknown country code \"ZZ\""
+    );
+
+    // Display and as_str use the uppercase code.
+    assert_eq!(CountryCode::US.as_str(), "US");
+    assert_eq!(CountryCode::US.to_string(), "US");
+
+    // Typed codes plug into every code-based conversion.
+    assert_eq!(code_to_name(CountryCode::JP), Some("Japan"));
+    assert_eq!(code_to_name_in(CountryCode::JP, Locale::En), Some("Japan"));
+    assert_eq!(code_to_flag(CountryCode::JP), Some("🇯🇵".to_string()));
+    assert_eq!(code_to_demonym(CountryCode::JP), Some("Japanese"));
+    assert_eq!(
+        code_to_endonyms(CountryCode::JP).map(|endonyms| endonyms[0]),
+        Some("日本")
+    );
+    assert_eq!(
+        code_to_country(CountryCode::JP).map(Country::code),
+        Some("JP")
+    );
+    assert_eq!(alpha2_to_alpha3(CountryCode::JP), Some("JPN"));
+    assert_eq!(code_to_numeric(CountryCode::JP), Some("392"));
+    assert_eq!(code_status(CountryCode::JP), Some(CodeStatus::Official));
+    assert_eq!(CountryCode::JP.name(), "Japan");
+    assert_eq!(CountryCode::JP.flag(), "🇯🇵");
+    assert_eq!(country("Japan").unwrap().country_code(), CountryCode::JP);
+
+    // Every variant has a dataset entry.
+    for &country_code in CountryCode::ALL {
+        let entry = country_code.country();
+        assert_eq!(entry.code(), country_code.as_str());
+        assert_eq!(entry.country_code(), country_code);
+    }
+
+    // Every dataset entry has a variant.
+    for first in 'A'..='Z' {
+        for second in 'A'..='Z' {
+            let candidate = format!("{first}{second}");
+            assert_eq!(
+                code_to_country(&candidate).is_some(),
+                candidate.parse::<CountryCode>().is_ok(),
+                "{candidate}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_code_status() {
+    // Officially assigned codes, including dependent territories.
+    for official in ["US", "DE", "PR", "GU", "AQ", "TW", "PS"] {
+        assert_eq!(
+            code_status(official),
+            Some(CodeStatus::Official),
+            "{official}"
+        );
+    }
+
+    // Exceptionally reserved codes.
+    for reserved in ["AC", "CP", "CQ", "DG", "EA", "EU", "IC", "TA", "UN"] {
+        assert_eq!(
+            code_status(reserved),
+            Some(CodeStatus::ExceptionallyReserved),
+            "{reserved}"
+        );
+    }
+
+    // Transitional and user-assigned codes.
+    assert_eq!(code_status("AN"), Some(CodeStatus::TransitionallyReserved));
+    assert_eq!(code_status("XK"), Some(CodeStatus::UserAssigned));
+
+    // Status is available on Country values and does not affect flag rendering.
+    let eu = code_to_country("EU").unwrap();
+    assert_eq!(eu.status(), CodeStatus::ExceptionallyReserved);
+    assert!(!eu.status().is_official());
+    assert_eq!(code_to_flag("EU"), Some("🇪🇺".to_string()));
+
+    // Invalid codes.
+    assert_eq!(code_status("ZZ"), None);
+    assert_eq!(code_status("USA"), None);
+}
+
+#[test]
+fn test_strict_lookups() {
+    // Official entries resolve the same way in both modes.
+    for input in ["Germany", "DEU", "🇩🇪", "Federal Republic of Germany", "276"] {
+        assert_eq!(code_strict(input), Some("DE"), "{input}");
+        assert_eq!(code_strict(input), code(input), "{input}");
+    }
+    assert_eq!(name_to_code_strict("UK"), Some("GB"));
+    assert_eq!(name_to_code_strict("Puerto Rico"), Some("PR"));
+
+    // Non-official entries are rejected in strict mode only.
+    for (input, expected) in [
+        ("European Union", "EU"),
+        ("United Nations", "UN"),
+        ("Netherlands Antilles", "AN"),
+        ("Kosovo", "XK"),
+        ("Ascension Island", "AC"),
+        ("Canary Islands", "IC"),
+    ] {
+        assert_eq!(code(input), Some(expected), "{input}");
+        assert_eq!(code_strict(input), None, "{input}");
+        assert_eq!(name_to_code_strict(input), None, "{input}");
+    }
+    assert_eq!(code_strict("🇪🇺"), None);
+    assert_eq!(code_strict("🇺🇳"), None);
+    assert_eq!(code_strict("XK"), None);
+    assert_eq!(code_strict("XKX"), None);
+
+    // is_code_strict only accepts officially assigned codes.
+    assert!(is_code_strict(Some("US")));
+    assert!(is_code_strict(Some("pr")));
+    for reserved in ["EU", "UN", "AN", "XK", "AC", "TA"] {
+        assert!(is_code(Some(reserved)), "{reserved}");
+        assert!(!is_code_strict(Some(reserved)), "{reserved}");
+    }
+    assert!(!is_code_strict(Some("ZZ")));
+    assert!(!is_code_strict(None));
+}
+
+#[test]
+fn test_name_candidates() {
+    // Exact matches come first with a perfect score.
+    let candidates = name_candidates("Niger", 5);
+    assert_eq!(candidates[0], ("NE", 1.0));
+    assert_eq!(candidates[1].0, "NG");
+    assert!(candidates[1].1 < 1.0);
+
+    // Scores are sorted in descending order and each code appears once.
+    for window in candidates.windows(2) {
+        assert!(window[0].1 >= window[1].1);
+    }
+    let mut codes: Vec<&str> = candidates.iter().map(|(code, _)| *code).collect();
+    codes.sort_unstable();
+    codes.dedup();
+    assert_eq!(codes.len(), candidates.len());
+
+    // Ambiguous input yields every plausible candidate.
+    let korea: Vec<&str> = name_candidates("Korea", 2)
+        .into_iter()
+        .map(|(code, _)| code)
+        .collect();
+    assert_eq!(korea.len(), 2);
+    assert!(korea.contains(&"KP"));
+    assert!(korea.contains(&"KR"));
+
+    // The winner agrees with name_to_code when it resolves.
+    for input in ["Vatican", "Republic of Moldova", "Virgin Islands, British"] {
+        assert_eq!(
+            name_candidates(input, 1).first().map(|(code, _)| *code),
+            name_to_code(input),
+            "{input}"
+        );
+    }
+
+    // Limits and empty results.
+    assert_eq!(name_candidates("Niger", 1).len(), 1);
+    assert!(name_candidates("Niger", 0).is_empty());
+    assert!(name_candidates("", 5).is_empty());
+    assert!(name_candidates("Atlantis", 5).is_empty());
+}
+
+#[test]
+fn test_name_match() {
+    for (input, expected_code, expected_kind) in [
+        ("Germany", "DE", MatchKind::Exact),
+        ("united kingdom", "GB", MatchKind::Exact),
+        ("UAE", "AE", MatchKind::Exact),
+        ("Cote  d'Ivoire", "CI", MatchKind::Normalized),
+        ("St Lucia", "LC", MatchKind::Normalized),
+        ("St. Kitts and Nevis", "KN", MatchKind::Normalized),
+        ("United   States", "US", MatchKind::Normalized),
+        ("Republic of France", "FR", MatchKind::GovernmentPattern),
+        ("Kingdom of Spain", "ES", MatchKind::GovernmentPattern),
+        ("Moldova, Republic of", "MD", MatchKind::GovernmentPattern),
+        ("Holy See (Vatican City State)", "VA", MatchKind::Fuzzy),
+        ("Kingdom of the Netherlands", "NL", MatchKind::Fuzzy),
+    ] {
+        let found = name_match(input).unwrap_or_else(|| panic!("{input}"));
+        assert_eq!(found.code(), expected_code, "{input}");
+        assert_eq!(found.kind(), expected_kind, "{input}");
+        assert_eq!(found.country().code(), expected_code, "{input}");
+
+        if expected_kind == MatchKind::Fuzzy {
+            assert!(found.score() > 0.0 && found.score() < 1.0, "{input}");
+        } else {
+            assert_eq!(found.score(), 1.0, "{input}");
+        }
+    }
+
+    // name_match agrees with name_to_code.
+    for input in [
+        "Korea",
+        "United",
+        "Atlantis",
+        "",
+        "Virgin Islands, British",
+        "Congo",
+    ] {
+        assert_eq!(
+            name_match(input).map(|found| found.code()),
+            name_to_code(input)
+        );
+    }
+}
+
+#[test]
+fn test_lookup_errors() {
+    // Successful lookups match code() and name_to_code().
+    for input in ["🇨🇦", "CAN", "Canada", "UK", "Republic of Korea", "Vatican"] {
+        assert_eq!(try_code(input).ok(), code(input), "{input}");
+        assert_eq!(try_name_to_code(input).ok(), name_to_code(input), "{input}");
+    }
+
+    // Ambiguous names list every plausible country.
+    assert_eq!(
+        try_code("Korea"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["KP", "KR"]
+        })
+    );
+    assert_eq!(
+        try_name_to_code("korea"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["KP", "KR"]
+        })
+    );
+
+    // "Congo" is ambiguous, even though fuzzy matching resolves it to CD.
+    assert_eq!(
+        try_code("Congo"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["CD", "CG"]
+        })
+    );
+    assert_eq!(
+        Matcher::builder()
+            .fuzzy(false)
+            .build()
+            .try_name_to_code("Congo"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["CD", "CG"]
+        })
+    );
+    assert_eq!(
+        try_code("Virgin"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["VG", "VI"]
+        })
+    );
+
+    // Generic words are reported separately from unknown names.
+    for generic in [
+        "United",
+        "Republic",
+        "Island",
+        "New",
+        "the United States of",
+    ] {
+        assert_eq!(try_code(generic), Err(LookupError::TooGeneric), "{generic}");
+    }
+
+    // Unknown input.
+    // Weak fuzzy candidates are not reported as ambiguous.
+    for unknown in ["Atlantis", "Atlantis Islands", "", "   ", "ZZ", "🎌"] {
+        assert_eq!(try_code(unknown), Err(LookupError::NotFound), "{unknown:?}");
+    }
+
+    // Errors have readable messages.
+    assert_eq!(
+        try_code("Korea").unwrap_err().to_string(),
+        "ambiguous country name, candidates: KP, KR"
+    );
+    assert_eq!(
+        LookupError::NotFound.to_string(),
+        "no matching country found"
+    );
+}
+
+#[test]
+fn test_custom_matcher() {
+    let default_matcher = Matcher::default();
+    for input in [
+        "Germany",
+        "Republic of France",
+        "Holy See (Vatican City State)",
+        "Korea",
+        "Atlantis",
+    ] {
+        assert_eq!(
+            default_matcher.name_match(input),
+            name_match(input),
+            "{input}"
+        );
+    }
+
+    let exact_only = Matcher::builder().fuzzy(false).build();
+    assert_eq!(exact_only.name_to_code("St. Lucia"), Some("LC"));
+    assert_eq!(exact_only.name_to_code("Republic of France"), Some("FR"));
+    assert_eq!(
+        exact_only.name_to_code("Holy See (Vatican City State)"),
+        None
+    );
+    assert_eq!(exact_only.code("🇫🇷"), Some("FR"));
+    assert_eq!(exact_only.code("FRA"), Some("FR"));
+
+    let no_titles = Matcher::builder().government_patterns(false).build();
+    let france = no_titles.name_match("Republic of France").unwrap();
+    assert_eq!(france.code(), "FR");
+    assert_eq!(france.kind(), MatchKind::Fuzzy);
+
+    let no_reversal = Matcher::builder()
+        .comma_reversal(false)
+        .fuzzy(false)
+        .build();
+    assert_eq!(name_to_code("Lucia, Saint"), Some("LC"));
+    assert_eq!(no_reversal.name_to_code("Lucia, Saint"), None);
+
+    let strict_threshold = Matcher::builder()
+        .single_word_threshold(0.9)
+        .multi_word_threshold(0.9)
+        .build();
+    assert_eq!(
+        strict_threshold.name_to_code("Holy See (Vatican City State)"),
+        None
+    );
+    assert_eq!(strict_threshold.name_to_code("Vatican"), Some("VA"));
+
+    let generic = Matcher::builder()
+        .generic_words(["Country", "NATION"])
+        .build();
+    assert_eq!(try_name_to_code("Country"), Err(LookupError::NotFound));
+    assert_eq!(
+        generic.try_name_to_code("country"),
+        Err(LookupError::TooGeneric)
+    );
+    assert_eq!(
+        generic.try_name_to_code("Nation"),
+        Err(LookupError::TooGeneric)
+    );
+    assert_eq!(generic.try_code("Nation"), Err(LookupError::TooGeneric));
+    assert_eq!(generic.name_to_code("Canada"), Some("CA"));
+}
+
+#[test]
+fn test_typo_tolerance() {
+    for (input, expected) in [
+        ("Germny", "DE"),
+        ("Phillipines", "PH"),
+        ("Argentia", "AR"),
+        ("Swtizerland", "CH"),
+        ("Untied Kingdom", "GB"),
+        ("New Zeland", "NZ"),
+        ("Luxembrug", "LU"),
+    ] {
+        let found = name_match(input).unwrap_or_else(|| panic!("{input} did not match"));
+        assert_eq!(found.code(), expected, "{input}");
+        assert_eq!(found.kind(), MatchKind::Fuzzy, "{input}");
+        assert!(found.score() < 1.0, "{input}");
+    }
+
+    // Short words must match exactly, and typos do not resolve ambiguity.
+    assert_eq!(code("Irqa"), None);
+    assert_eq!(
+        try_code("Korae"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["KP", "KR"]
+        })
+    );
+
+    let exact_words = Matcher::builder().typo_tolerance(false).build();
+    assert_eq!(exact_words.name_to_code("Germny"), None);
+    assert_eq!(exact_words.name_to_code("Germany"), Some("DE"));
+}
+
+#[test]
+fn test_country_registry() {
+    let mut registry = CountryRegistry::new();
+    for input in ["Germany", "UK", "DEU", "🇯🇵", "Korea", "Republic of France"] {
+        assert_eq!(registry.code(input), code(input), "{input}");
+        assert_eq!(registry.try_code(input), try_code(input), "{input}");
+    }
+
+    registry.add_alias("DE", "Teutonia").unwrap();
+    registry
+        .add_alias(CountryCode::MK, "Macedonia FYR")
+        .unwrap();
+    registry.add_alias("cz", "Tschechei").unwrap();
+    assert_eq!(name_to_code("Teutonia"), None);
+    assert_eq!(
+        registry.name_match("TEUTONIA").unwrap().kind(),
+        MatchKind::Exact
+    );
+    assert_eq!(registry.name_to_code("Republic of Teutonia"), Some("DE"));
+    assert_eq!(registry.name_to_code("Teutonai"), Some("DE"));
+    assert_eq!(registry.name_to_code("Macedonia, FYR"), Some("MK"));
+    assert_eq!(registry.country("Tschechei").map(|c| c.code()), Some("CZ"));
+    assert_eq!(registry.aliases("DE").collect::<Vec<_>>(), ["Teutonia"]);
+
+    // Custom aliases take precedence over built-in names.
+    registry.add_alias("CG", "Congo").unwrap();
+    registry.add_alias("AD", "Germany").unwrap();
+    assert_eq!(code("Congo"), None);
+    assert_eq!(registry.code("Congo"), Some("CG"));
+    assert_eq!(registry.code("Germany"), Some("AD"));
+
+    assert!(registry.remove_alias("GB", "uk"));
+    assert!(!registry.remove_alias("GB", "UK"));
+    assert!(!registry.remove_alias("GB", "United Kingdom"));
+    assert_eq!(registry.name_to_code("UK"), None);
+    assert_eq!(registry.name_to_code("United Kingdom"), Some("GB"));
+    assert_eq!(registry.name_to_code("Teutonia"), Some("DE"));
+
+    // Redirects survive removing unrelated aliases, and can be removed themselves.
+    assert_eq!(registry.code("Germany"), Some("AD"));
+    assert_eq!(registry.code("Congo"), Some("CG"));
+    assert!(registry.remove_alias("AD", "Germany"));
+    assert_eq!(registry.code("Germany"), Some("DE"));
+    assert_eq!(registry.aliases("AD").count(), 0);
+
+    // Demonyms can be removed like aliases.
+    assert_eq!(registry.code("American"), Some("US"));
+    assert!(registry.remove_alias("US", "american"));
+    assert!(!registry.remove_alias("US", "American"));
+    // Without the demonym, "American" is only a partial name.
+    assert_eq!(registry.code("American"), Some("AS"));
+    assert_eq!(registry.code("United States"), Some("US"));
+    assert_eq!(code("American"), Some("US"));
+
+    assert_eq!(
+        registry.add_alias("ZZ", "Zedland").unwrap_err().to_string(),
+        "unknown country code \"ZZ\""
+    );
+    assert!(!registry.remove_alias("ZZ", "Zedland"));
+
+    let exact_only = registry.with_matcher(Matcher::builder().fuzzy(false).build());
+    assert_eq!(exact_only.name_to_code("Teutonai"), None);
+    assert_eq!(exact_only.name_to_code("Teutonia"), Some("DE"));
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_registry_from_csv() {
+    use country_emoji::{DataFormat, LoadError, LoadMode};
+
+    let data = "code,name,aliases\n\
+                nl,Holland,Nederland;\"Netherlands, Kingdom of the\"\n\
+                MK,,Macedonia FYR\n\
+                DE,Germany\n";
+
+    let extended =
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Extend).unwrap();
+    assert_eq!(extended.name("NL"), Some("Netherlands"));
+    assert_eq!(extended.code("Holland"), Some("NL"));
+    assert_eq!(extended.code("nederland"), Some("NL"));
+    assert_eq!(extended.code("Macedonia FYR"), Some("MK"));
+    assert_eq!(extended.code("France"), Some("FR"));
+    assert_eq!(extended.code("🇫🇷"), Some("FR"));
+
+    let data = "code,name,aliases\n\
+                NL,Holland,Nederland\n\
+                MK,North Macedonia,Macedonia FYR\n";
+    let replaced =
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Replace).unwrap();
+    assert_eq!(replaced.name("NL"), Some("Holland"));
+    assert_eq!(replaced.aliases("NL").collect::<Vec<_>>(), ["Nederland"]);
+    assert_eq!(replaced.code("Holland"), Some("NL"));
+    assert_eq!(replaced.code("Netherlands"), None);
+    assert_eq!(replaced.code("MKD"), Some("MK"));
+    assert_eq!(replaced.code("FR"), None);
+    assert_eq!(replaced.code("🇫🇷"), None);
+    assert_eq!(replaced.name("FR"), None);
+
+    // Replacement data also replaces built-in demonyms, native names, and localized names.
+    let data = "code,name
+DE,Bundesrepublik
+FR,Frankreich
+";
+    let replaced =
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Replace).unwrap();
+    assert_eq!(replaced.code("Frankreich"), Some("FR"));
+    for name in ["French", "German", "Deutschland", "France", "Allemagne"] {
+        assert_eq!(replaced.code(name), None, "{name}");
+    }
+
+    let load = |data: &str| {
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Csv, LoadMode::Extend)
+            .unwrap_err()
+    };
+
+    let error = load("code,name\nNL,Holland\nZZ,Zedland\n");
+    assert!(matches!(error, LoadError::InvalidCode { row: 2, ref code } if code == "ZZ"));
+    assert_eq!(error.to_string(), "row 2: unknown country code \"ZZ\"");
+
+    let error = load("code,name\nNL,Holland\nnl,Nederland\n");
+    assert!(matches!(
+        error,
+        LoadError::DuplicateCode { row: 2, code: "NL" }
+    ));
+
+    let error = load("code,name,aliases\nCD,,Congo\nCG,Congo-Brazzaville,CONGO\n");
+    assert!(matches!(
+        error,
+        LoadError::ConflictingAlias {
+            row: 2,
+            code: "CG",
+            existing_code: "CD",
+            ..
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "row 2: \"CONGO\" for CG is already used for CD"
+    );
+
+    // Extending names must not take over names of the built-in dataset.
+    let error = load("code,name,aliases\nNL,Holland\nAD,,germany\n");
+    assert!(matches!(
+        error,
+        LoadError::ConflictingAlias {
+            row: 2,
+            code: "AD",
+            existing_code: "DE",
+            ..
+        }
+    ));
+    assert!(matches!(
+        load("code,name\nFR,French Guiana\n"),
+        LoadError::ConflictingAlias {
+            existing_code: "GF",
+            ..
+        }
+    ));
+    assert!(matches!(
+        load("code,name\nGB,American\n"),
+        LoadError::ConflictingAlias {
+            existing_code: "US",
+            ..
+        }
+    ));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_registry_from_json() {
+    use country_emoji::{DataFormat, LoadError, LoadMode};
+    use std::error::Error;
+
+    let data = r#"[
+        {"code": "DE", "name": "Deutschland", "aliases": ["BRD", "Germany"]},
+        {"code": "AT", "name": "Österreich"}
+    ]"#;
+
+    let replaced =
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Json, LoadMode::Replace).unwrap();
+    assert_eq!(replaced.name("de"), Some("Deutschland"));
+    assert_eq!(replaced.code("Osterreich"), Some("AT"));
+    assert_eq!(replaced.code("Germany"), Some("DE"));
+    assert_eq!(replaced.code("Switzerland"), None);
+
+    let extended =
+        CountryRegistry::from_reader(data.as_bytes(), DataFormat::Json, LoadMode::Extend).unwrap();
+    assert_eq!(extended.name("DE"), Some("Germany"));
+    assert_eq!(extended.code("BRD"), Some("DE"));
+    assert_eq!(extended.code("Switzerland"), Some("CH"));
+
+    let error = CountryRegistry::from_reader(
+        r#"[{"code": "AT"}]"#.as_bytes(),
+        DataFormat::Json,
+        LoadMode::Replace,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        LoadError::MissingName { row: 1, code: "AT" }
+    ));
+
+    let error = CountryRegistry::from_reader(
+        r#"[{"name": "Austria"}]"#.as_bytes(),
+        DataFormat::Json,
+        LoadMode::Extend,
+    )
+    .unwrap_err();
+    assert!(matches!(error, LoadError::Json(_)));
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn test_localized_names() {
+    assert_eq!(code_to_name_in("DE", Locale::En), Some("Germany"));
+    assert_eq!(code_to_name_in(CountryCode::JP, Locale::En), Some("Japan"));
+    assert_eq!(code_to_name_in("ZZ", Locale::En), None);
+    assert_eq!(country("Brazil").unwrap().name_in(Locale::En), "Brazil");
+
+    assert_eq!("en".parse::<Locale>(), Ok(Locale::En));
+    assert_eq!("EN-us".parse::<Locale>(), Ok(Locale::En));
+    assert!("xx".parse::<Locale>().is_err());
+    assert!(Locale::ALL.contains(&Locale::En));
+
+    // Every enabled locale names every country in the dataset.
+    for &locale in Locale::ALL {
+        assert_eq!(locale.as_str().parse::<Locale>(), Ok(locale));
+        for &code in CountryCode::ALL {
+            assert!(
+                code_to_name_in(code, locale).is_some(),
+                "{code} has no {locale} name"
+            );
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "locale-ar",
+    feature = "locale-de",
+    feature = "locale-es",
+    feature = "locale-fr",
+    feature = "locale-ja",
+    feature = "locale-pt",
+    feature = "locale-ru",
+    feature = "locale-sv",
+    feature = "locale-zh"
+))]
+#[test]
+fn test_localized_names_for_all_locales() {
+    assert_eq!(code_to_name_in("DE", Locale::Pt), Some("Alemanha"));
+    assert_eq!(code_to_name_in("DE", Locale::Es), Some("Alemania"));
+    assert_eq!(code_to_name_in("DE", Locale::Fr), Some("Allemagne"));
+    assert_eq!(code_to_name_in("DE", Locale::De), Some("Deutschland"));
+    assert_eq!(code_to_name_in("DE", Locale::Ja), Some("ドイツ"));
+    assert_eq!(code_to_name_in("DE", Locale::Zh), Some("德国"));
+    assert_eq!(code_to_name_in("DE", Locale::Sv), Some("Tyskland"));
+
+    // Short display forms rather than ISO's inverted formal names.
+    assert_eq!(code_to_name_in("KR", Locale::De), Some("Südkorea"));
+    assert_eq!(code_to_name_in("RU", Locale::Fr), Some("Russie"));
+    assert_eq!(code_to_name_in("VE", Locale::Es), Some("Venezuela"));
+    assert_eq!(code_to_name_in("us", Locale::Fr), Some("États-Unis"));
+
+    assert_eq!("pt-BR".parse::<Locale>(), Ok(Locale::Pt));
+    assert_eq!("zh_Hans".parse::<Locale>(), Ok(Locale::Zh));
+    assert_eq!(Locale::ALL.len(), 10);
+    assert_eq!(
+        CountryCode::CI.country().name_in(Locale::Pt),
+        "Costa do Marfim"
+    );
+}
+
+#[cfg(all(
+    feature = "locale-ar",
+    feature = "locale-de",
+    feature = "locale-es",
+    feature = "locale-fr",
+    feature = "locale-ja",
+    feature = "locale-pt",
+    feature = "locale-ru",
+    feature = "locale-sv",
+    feature = "locale-zh"
+))]
+#[test]
+fn test_multilingual_name_matching() {
+    for (input, expected) in [
+        ("Alemanha", "DE"),
+        ("Allemagne", "DE"),
+        ("Deutschland", "DE"),
+        ("ALEMANIA", "DE"),
+        ("ドイツ", "DE"),
+        ("德国", "DE"),
+        ("ألمانيا", "DE"),
+        ("Tyskland", "DE"),
+        ("日本", "JP"),
+        ("Россия", "RU"),
+        ("россия", "RU"),
+        ("Costa do Marfim", "CI"),
+        ("Émirats arabes unis", "AE"),
+        ("Emirats arabes unis", "AE"),
+        ("Vereinigte Staaten", "US"),
+        ("Südkorea", "KR"),
+        ("Sudkorea", "KR"),
+    ] {
+        let found = name_match(input).unwrap_or_else(|| panic!("{input} did not match"));
+        assert_eq!(found.code(), expected, "{input}");
+        assert_eq!(code(input), Some(expected), "{input}");
+    }
+
+    // Localized names never shadow English names or ambiguity, and stay out of fuzzy
+    // matching.
+    assert_eq!(code("Congo"), None);
+    assert_eq!(code_to_name_in("CG", Locale::Es), Some("Congo"));
+    assert_eq!(
+        try_code("Congo"),
+        Err(LookupError::Ambiguous {
+            candidates: vec!["CD", "CG"]
+        })
+    );
+    assert_eq!(code("Korea"), None);
+    assert_eq!(code("Guinea"), Some("GN"));
+    assert_eq!(code("Dominica"), Some("DM"));
+    assert_eq!(name_to_code("Alemanhia"), None);
+
+    // Registries keep localized names when their index is rebuilt.
+    let mut registry = CountryRegistry::new();
+    registry.add_alias("NL", "Holland").unwrap();
+    assert!(registry.remove_alias("NL", "Holland"));
+    assert_eq!(registry.code("Alemanha"), Some("DE"));
+}
+
+#[test]
+fn test_subdivision_flags() {
+    let england = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+    let scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+    let wales = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}";
+
+    assert_eq!(code(england), Some("GB-ENG"));
+    assert_eq!(code(scotland), Some("GB-SCT"));
+    assert_eq!(code(wales), Some("GB-WLS"));
+    assert_eq!(try_code(scotland), Ok("GB-SCT"));
+    assert_eq!(flag_to_subdivision(scotland), Some("GB-SCT"));
+    assert_eq!(name(wales), Some("Wales"));
+
+    assert_eq!(subdivision_to_flag("GB-ENG").as_deref(), Some(england));
+    assert_eq!(subdivision_to_flag(" gb-sct ").as_deref(), Some(scotland));
+    assert_eq!(flag("Scotland").as_deref(), Some(scotland));
+    assert_eq!(flag("Cymru").as_deref(), Some(wales));
+    assert_eq!(flag("GB-ENG").as_deref(), Some(england));
+    assert_eq!(code("scotland"), Some("GB-SCT"));
+    assert_eq!(code("GB-WLS"), Some("GB-WLS"));
+
+    // Countries resolve to the United Kingdom, while name lookups only return ISO 3166-1 codes.
+    assert_eq!(country("Scotland").map(Country::code), Some("GB"));
+    assert_eq!(country(wales).map(Country::code), Some("GB"));
+    assert_eq!(code_strict("Scotland"), None);
+    for name in ["England", "Scotland", "Wales", "Cymru"] {
+        assert_eq!(name_to_code(name), None, "{name}");
+        assert_eq!(name_to_code_strict(name), None, "{name}");
+        assert_eq!(name_match(name), None, "{name}");
+        assert_eq!(try_name_to_code(name), Err(LookupError::NotFound), "{name}");
+        assert_eq!(name_to_country(name), None, "{name}");
+    }
+
+    // Country flags and unknown or malformed tag sequences are not subdivisions.
+    assert_eq!(flag_to_subdivision("🇬🇧"), None);
+    assert_eq!(flag_to_code(scotland), None);
+    assert!(!is_country_flag(scotland));
+    assert_eq!(code("\u{1F3F4}"), None);
+    assert_eq!(
+        code("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}"),
+        None
+    );
+    assert_eq!(
+        flag_to_subdivision("\u{1F3F4}\u{E0075}\u{E0073}\u{E0074}\u{E0078}\u{E007F}"),
+        None
+    );
+    assert_eq!(subdivision_to_flag("GB-NIR"), None);
+    assert_eq!(subdivision_to_flag("GB"), None);
+}
+
+#[test]
+fn test_find_flags() {
+    let flags = |text: &str| find_flags(text).map(|(_, code)| code).collect::<Vec<_>>();
+
+    assert_eq!(flags("🇺🇸🇨🇦"), ["US", "CA"]);
+    assert_eq!(flags("I ❤️ 🇧🇷 and 🇵🇹!"), ["BR", "PT"]);
+    assert_eq!(flags("🇸🇨"), ["SC"]);
+    assert_eq!(flags("🇿🇿🇺🇸"), ["US"]);
+    assert_eq!(flags("🇺🇸🇨"), ["US"]);
+    assert_eq!(flags("🇺 🇸🇨🇦"), ["SC"]);
+    assert!(flags("no flags here 🏳️ 🏴 🎌").is_empty());
+    assert!(flags("").is_empty());
+
+    let scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+    let text = format!("Go {scotland}🇫🇷{scotland}");
+    let found: Vec<_> = find_flags(&text).collect();
+    assert_eq!(found.len(), 3);
+    assert_eq!(found[0], (3..3 + scotland.len(), "GB-SCT"));
+    assert_eq!(&text[found[1].0.clone()], "🇫🇷");
+    assert_eq!(found[1].1, "FR");
+    assert_eq!(&text[found[2].0.clone()], scotland);
+
+    // Every range lies on character boundaries and is a flag on its own.
+    let text = "🇯🇵Tokyo🇩🇪🇪🇺 Berlin";
+    for (range, code) in find_flags(text) {
+        assert_eq!(flag_to_code(&text[range]), Some(code));
+    }
+}
+
+#[test]
+fn test_flag_replacement() {
+    assert_eq!(
+        flags_to_names("Going to 🇯🇵 next week"),
+        "Going to [Japan] next week"
+    );
+    assert_eq!(flags_to_names("🇺🇸🇨🇦"), "[United States][Canada]");
+    assert_eq!(flags_to_names("no flags 🏳️"), "no flags 🏳️");
+    assert_eq!(
+        replace_flags("🇧🇷 x 🇦🇷", |code| code.to_lowercase()),
+        "br x ar"
+    );
+    assert_eq!(replace_flags("🇧🇷 x 🇦🇷", |code| code), "BR x AR");
+
+    assert_eq!(codes_to_flags(":JP:"), "🇯🇵");
+    assert_eq!(codes_to_flags("[JP] and :BR:"), "🇯🇵 and 🇧🇷");
+    assert_eq!(codes_to_flags("(:FR:)"), "(🇫🇷)");
+    assert_eq!(
+        codes_to_flags(":GB-WLS: [GB-ENG]"),
+        "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F} \
+         \u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}"
+    );
+    assert_eq!(
+        codes_to_flags("[jp] :ZZ: [JP: :JPN: [Japan]"),
+        "[jp] :ZZ: [JP: :JPN: [Japan]"
+    );
+    assert_eq!(codes_to_flags("10:30:00 [1]"), "10:30:00 [1]");
+
+    // Tokens only stand on their own, never inside times or runs of delimiters.
+    for text in [
+        "10:30:DE:",
+        "::DE::",
+        "a:b:c:DE:",
+        ":DE:s",
+        "x[DE]",
+        "[[DE]]",
+    ] {
+        assert_eq!(codes_to_flags(text), text);
+    }
+    assert_eq!(codes_to_flags(""), "");
+
+    // Adjacent tokens are each replaced.
+    assert_eq!(codes_to_flags("[US][CA]"), "🇺🇸🇨🇦");
+    assert_eq!(codes_to_flags(":US::CA:"), "🇺🇸🇨🇦");
+    assert_eq!(codes_to_flags("(:US::CA::MX:)"), "(🇺🇸🇨🇦🇲🇽)");
+    assert_eq!(codes_to_flags("[US]:CA: [BR][AR]"), "🇺🇸🇨🇦 🇧🇷🇦🇷");
+
+    // Round trips.
+    let text = "Visiting 🇯🇵, 🇰🇷 and 🇹🇼.";
+    assert_eq!(
+        codes_to_flags(&replace_flags(text, |code| format!(":{code}:"))),
+        text
+    );
+}
+
+#[test]
+fn test_find_countries() {
+    let mentions = |text: &'static str| {
+        find_countries(text)
+            .map(|(range, code)| (&text[range], code))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        mentions("flights from Brazil to the United States via St. Lucia"),
+        [
+            ("Brazil", "BR"),
+            ("United States", "US"),
+            ("St. Lucia", "LC")
+        ]
+    );
+    assert_eq!(
+        mentions("South Sudan, Sudan, and Papua New Guinea"),
+        [
+            ("South Sudan", "SS"),
+            ("Sudan", "SD"),
+            ("Papua New Guinea", "PG")
+        ]
+    );
+    assert_eq!(
+        mentions("Côte d'Ivoire & Guinea-Bissau"),
+        [("Côte d'Ivoire", "CI"), ("Guinea-Bissau", "GW")]
+    );
+    assert_eq!(
+        mentions("The Democratic Republic of the Congo borders the Republic of the Congo."),
+        [
+            ("The Democratic Republic of the Congo", "CD"),
+            ("Republic of the Congo", "CG")
+        ]
+    );
+    assert_eq!(
+        mentions("Made in Deutschland, shipped to UK"),
+        [("Deutschland", "DE"), ("UK", "GB")]
+    );
+
+    // Lowercase words, demonyms, and ambiguous names are not mentions.
+    assert!(mentions("tell us about the turkey").is_empty());
+    assert!(mentions("British and American fans").is_empty());
+    assert!(mentions("Korea").is_empty());
+    assert_eq!(mentions("New York, Ireland"), [("Ireland", "IE")]);
+    assert!(mentions("").is_empty());
+
+    // Possessives end a mention, with either apostrophe.
+    assert_eq!(
+        mentions("Brazil's president met France's"),
+        [("Brazil", "BR"), ("France", "FR")]
+    );
+    assert_eq!(mentions("South Korea's economy"), [("South Korea", "KR")]);
+    assert_eq!(
+        mentions("Japan’s and the United States’ exports"),
+        [("Japan", "JP"), ("United States", "US")]
+    );
+
+    // Countries inside longer capitalized names are not mentions, unless the name is known.
+    assert!(mentions("New Jersey").is_empty());
+    assert!(mentions("flights to New Jersey").is_empty());
+    assert_eq!(
+        mentions("In France and New Zealand"),
+        [("France", "FR"), ("New Zealand", "NZ")]
+    );
+
+    // Comma-reversed names.
+    assert_eq!(
+        mentions("the Virgin Islands, British and Korea, Republic of"),
+        [
+            ("Virgin Islands, British", "VG"),
+            ("Korea, Republic of", "KR")
+        ]
+    );
+    assert_eq!(
+        mentions("Sudan, South Africa"),
+        [("Sudan", "SD"), ("South Africa", "ZA")]
+    );
+}
+
+#[test]
+fn test_suggest() {
+    assert_eq!(suggest("uni", 3), ["US", "GB", "AE"]);
+    assert_eq!(suggest("UNITED K", 5), ["GB"]);
+    assert_eq!(suggest("cô", 1), ["CI"]);
+    assert!(suggest("co", 10).contains(&"CI"));
+    assert_eq!(suggest("st vin", 5), ["VC"]);
+    assert_eq!(suggest("st. luc", 5), ["LC"]);
+    assert_eq!(suggest("st", 3), suggest("saint", 3));
+    assert_eq!(suggest("south s", 5), ["SS", "GS"]);
+    assert_eq!(suggest("kingdom", 5), ["GB"]);
+    assert_eq!(suggest("deutsch", 5), ["DE"]);
+    assert_eq!(suggest("  fra", 1), ["FR"]);
+
+    // Alternative English names rank before endonyms such as "Kòrsou".
+    assert_eq!(suggest("kor", 2), ["KP", "KR"]);
+
+    // Every prefix of a preferred name suggests it once.
+    for input in ["g", "ge", "ger", "germ", "germa", "german", "germany"] {
+        let suggestions = suggest(input, 300);
+        assert!(suggestions.contains(&"DE"), "{input}");
+        let mut unique = suggestions.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), suggestions.len(), "{input}");
+    }
+
+    assert_eq!(suggest("uni", 0), Vec::<&str>::new());
+    assert!(suggest("", 5).is_empty());
+    assert!(suggest("  ", 5).is_empty());
+    assert!(suggest("xyz", 5).is_empty());
+    assert!(suggest("-", 6).is_empty());
+    assert!(suggest(" - ", 6).is_empty());
+    assert!(suggest("42", 6).is_empty());
+}
+
+#[test]
+fn test_country_lists() {
+    let codes = |list: &CountryList| list.iter().map(Country::code).collect::<Vec<_>>();
+
+    assert_eq!(all_countries().len(), 260);
+    let all: Vec<_> = all_countries().map(Country::code).collect();
+    assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(codes(&CountryList::all()), all);
+
+    let official = codes(&CountryList::new());
+    assert_eq!(official.len(), 249);
+    assert!(official.contains(&"PR"));
+    assert!(!official
+        .iter()
+        .any(|code| ["AN", "EU", "XK", "AC"].contains(code)));
+    assert_eq!(CountryList::default(), CountryList::new());
+
+    let with_legacy = codes(&CountryList::new().include(CodeStatus::TransitionallyReserved));
+    assert!(with_legacy.contains(&"AN"));
+    assert!(!with_legacy.contains(&"EU"));
+
+    let reserved_only = CountryList::new()
+        .include(CodeStatus::ExceptionallyReserved)
+        .exclude(CodeStatus::Official);
+    assert_eq!(
+        codes(&reserved_only),
+        ["AC", "CP", "CQ", "DG", "EA", "EU", "IC", "TA", "UN"]
+    );
+    assert!(CountryList::new()
+        .exclude(CodeStatus::Official)
+        .iter()
+        .next()
+        .is_none());
+
+    // Territories can be left out to list only sovereign states, whatever their status.
+    let states = codes(&CountryList::new().territories(false));
+    assert_eq!(states.len(), 196);
+    assert!(states.contains(&"DK") && states.contains(&"VA"));
+    assert!(!states
+        .iter()
+        .any(|code| ["PR", "GL", "HK", "AQ", "VI"].contains(code)));
+    let reserved_states = codes(
+        &CountryList::all()
+            .territories(false)
+            .exclude(CodeStatus::Official),
+    );
+    assert_eq!(reserved_states, ["EU", "UN", "XK"]);
+
+    let by_name = CountryList::new().order(CountryOrder::Name(Locale::En));
+    let names: Vec<_> = by_name.iter().map(Country::name).collect();
+    assert_eq!(names.len(), 249);
+    assert_eq!(names[..3], ["Afghanistan", "Åland Islands", "Albania"]);
+    assert!(names
+        .windows(2)
+        .all(|pair| pair[0].to_lowercase() != pair[1].to_lowercase()));
+    let position = |name| names.iter().position(|&listed| listed == name).unwrap();
+    assert!(position("Côte d'Ivoire") < position("Croatia"));
+    assert!(position("Saint Barthélemy") < position("Saint Helena"));
+
+    for country in &by_name {
+        assert!(!country.flag().is_empty());
+    }
+}
+
+#[cfg(feature = "locale-de")]
+#[test]
+fn test_country_lists_by_localized_name() {
+    let list = CountryList::new().order(CountryOrder::Name(Locale::De));
+    let names: Vec<_> = list
+        .iter()
+        .map(|country| country.name_in(Locale::De))
+        .collect();
+    let position = |name| names.iter().position(|&listed| listed == name).unwrap();
+
+    assert!(position("Ägypten") < position("Albanien"));
+    assert!(position("Dänemark") < position("Deutschland"));
+    assert!(position("Oman") < position("Österreich"));
+}
+
+#[test]
+fn test_sorted_by_name() {
+    let sorted = sorted_by_name(Locale::En);
+    assert_eq!(
+        sorted,
+        CountryList::new()
+            .order(CountryOrder::Name(Locale::En))
+            .iter()
+            .collect::<Vec<_>>()
+    );
+
+    let names: Vec<_> = sorted.into_iter().map(Country::name).collect();
+    let position = |name| names.iter().position(|&listed| listed == name).unwrap();
+    assert_eq!(names.len(), 249);
+    assert_eq!(position("Åland Islands"), position("Afghanistan") + 1);
+    assert_eq!(position("Côte d'Ivoire"), position("Costa Rica") + 1);
+    assert_eq!(position("Curaçao"), position("Cuba") + 1);
+    assert_eq!(position("Saint Barthélemy"), position("Rwanda") + 1);
+    assert_eq!(position("São Tomé & Príncipe"), position("San Marino") + 1);
+    assert_eq!(names.last(), Some(&"Zimbabwe"));
+}
+
+#[cfg(feature = "locale-sv")]
+#[test]
+fn test_sorted_by_swedish_name() {
+    let names: Vec<_> = sorted_by_name(Locale::Sv)
+        .into_iter()
+        .map(|country| country.name_in(Locale::Sv))
+        .collect();
+    let position = |name| names.iter().position(|&listed| listed == name).unwrap();
+
+    assert_eq!(names[names.len() - 3..], ["Åland", "Österrike", "Östtimor"]);
+    assert!(position("Färöarna") > position("Frankrike"));
+    assert!(position("Västsahara") > position("Vietnam"));
+    assert_eq!(position("Côte d’Ivoire"), position("Costa Rica") + 1);
+}
+
+#[cfg(all(feature = "locale-ja", feature = "locale-ru"))]
+#[test]
+fn test_sorted_by_name_in_native_scripts() {
+    let names = |locale| {
+        sorted_by_name(locale)
+            .into_iter()
+            .map(|country| country.name_in(locale))
+            .collect::<Vec<_>>()
+    };
+
+    // Cyrillic order rather than the order of the transliterations.
+    let russian = names(Locale::Ru);
+    let position = |name| russian.iter().position(|&listed| listed == name).unwrap();
+    assert_eq!(russian[0], "Австралия");
+    assert!(position("Чад") > position("Франция"));
+    assert!(position("Эстония") < position("Япония"));
+
+    // Gojūon order, ignoring long vowel marks and voicing.
+    let japanese = names(Locale::Ja);
+    let position = |name| japanese.iter().position(|&listed| listed == name).unwrap();
+    assert!(position("オーストラリア") < position("オマーン"));
+    assert!(position("ガーナ") < position("カナダ"));
+    assert!(position("ドイツ") < position("ナイジェリア"));
+
+    // Names written with kanji sort by their reading among the katakana names.
+    assert!(position("カナダ") < position("韓国"));
+    assert!(position("韓国") < position("北朝鮮"));
+    assert!(position("北朝鮮") < position("ギニア"));
+    assert!(position("チャド") < position("中国"));
+    assert!(position("中国") < position("チリ"));
+    assert!(position("ニジェール") < position("日本"));
+    assert!(position("日本") < position("ニュージーランド"));
+    assert!(position("ボツワナ") < position("香港"));
+    assert!(position("香港") < position("ホンジュラス"));
+    assert!(position("ミクロネシア連邦") < position("南アフリカ"));
+    assert_eq!(japanese.last(), Some(&"ロシア"));
+}
+
+#[test]
+fn test_subdivisions() {
+    assert_eq!(subdivision_name("GB-SCT"), Some("Scotland"));
+    assert_eq!(subdivision_to_country("gb-eng"), Some("GB"));
+    assert_eq!(subdivision_name_to_code("Cymru"), Some("GB-WLS"));
+    assert_eq!(subdivision_name("GB"), None);
+    assert_eq!(subdivision_to_country("ZZ-AB"), None);
+    assert_eq!(subdivision_name_to_code("Atlantis"), None);
+}
+
+#[cfg(feature = "subdivisions")]
+#[test]
+fn test_subdivision_dataset() {
+    for (code, name, country) in [
+        ("US-CA", "California", "US"),
+        ("US-DC", "District of Columbia", "US"),
+        ("BR-SP", "São Paulo", "BR"),
+        ("DE-BY", "Bayern", "DE"),
+        ("CA-QC", "Quebec", "CA"),
+    ] {
+        assert_eq!(subdivision_name(code), Some(name));
+        assert_eq!(subdivision_name(&code.to_lowercase()), Some(name));
+        assert_eq!(subdivision_to_country(code), Some(country));
+        assert_eq!(subdivision_name_to_code(name), Some(code));
+    }
+
+    for (input, expected) in [
+        ("Sao Paulo", "BR-SP"),
+        ("SÃO PAULO", "BR-SP"),
+        ("State of São Paulo", "BR-SP"),
+        ("Sao Paolo", "BR-SP"),
+        ("Bavaria", "DE-BY"),
+        ("Thüringen", "DE-TH"),
+        ("Thuringen", "DE-TH"),
+        ("Québec", "CA-QC"),
+        ("Washington, D.C.", "US-DC"),
+        ("Washington", "US-WA"),
+        ("Pensylvania", "US-PA"),
+    ] {
+        assert_eq!(subdivision_name_to_code(input), Some(expected), "{input}");
+    }
+
+    // Partial names shared by several subdivisions are ambiguous.
+    for input in [
+        "Carolina",
+        "Dakota",
+        "Rio Grande",
+        "Columbia",
+        "North",
+        "New",
+    ] {
+        assert_eq!(subdivision_name_to_code(input), None, "{input}");
+    }
+    assert_eq!(subdivision_name_to_code("Jersey"), Some("US-NJ"));
+
+    // Subdivision names never leak into country-level lookups.
+    assert_eq!(code("Georgia"), Some("GE"));
+    assert_eq!(subdivision_name_to_code("Georgia"), Some("US-GA"));
+    assert_eq!(code("São Paulo"), None);
+
+    let exact_only = Matcher::builder().fuzzy(false).build();
+    assert_eq!(exact_only.subdivision_name_to_code("Sao Paolo"), None);
+    assert_eq!(
+        exact_only.subdivision_name_to_code("Sao Paulo"),
+        Some("BR-SP")
+    );
+}
+
+#[test]
+fn test_demonyms() {
+    assert_eq!(code_to_demonym("AD"), Some("Andorran"));
+    assert_eq!(code_to_demonym("ae"), Some("Emirati"));
+    assert_eq!(code_to_demonym(CountryCode::GB), Some("British"));
+    assert_eq!(code_to_demonym("SJ"), None);
+    assert_eq!(code_to_demonym("ZZ"), None);
+    assert_eq!(
+        CountryCode::EA.country().demonyms(),
+        ["Ceutí", "Melillense"]
+    );
+
+    assert_eq!(demonym_to_code("British"), Some("GB"));
+    assert_eq!(demonym_to_code("EMIRATI"), Some("AE"));
+    assert_eq!(demonym_to_code("Ceuti"), Some("EA"));
+    assert_eq!(demonym_to_code("Dominican"), Some("DO"));
+    assert_eq!(demonym_to_code("United Kingdom"), None);
+    assert_eq!(demonym_to_code("Britsh"), None);
+
+    // Demonyms are matched by default, but never as names of another country.
+    assert_eq!(code("British"), Some("GB"));
+    assert_eq!(code("American"), Some("US"));
+    assert_eq!(name_match("Andorran").unwrap().kind(), MatchKind::Demonym);
+    assert_eq!(name_candidates("Swiss", 1), [("CH", 1.0)]);
+
+    // Demonyms behind articles and government titles, and in fuzzy matching.
+    for (input, expected) in [
+        ("the French", "FR"),
+        ("the British", "GB"),
+        ("Republic of British", "GB"),
+        ("the Indian", "IN"),
+        ("the South African", "ZA"),
+        ("Republic of North Korean", "KP"),
+        ("the American", "US"),
+        ("BIOT Islanders", "IO"),
+        ("Bosnia", "BA"),
+        ("Antigua", "AG"),
+        ("Barbuda", "AG"),
+        ("Man", "IM"),
+    ] {
+        assert_eq!(name_to_code(input), Some(expected), "{input}");
+        assert_eq!(try_name_to_code(input), Ok(expected), "{input}");
+    }
+    // "Guiana" is the Portuguese name of Guyana.
+    #[cfg(not(feature = "locale-pt"))]
+    assert_eq!(name_to_code("Guiana"), Some("GF"));
+
+    let matcher = Matcher::builder().demonyms(false).build();
+    for input in ["the French", "Republic of British", "Bosnia", "Man"] {
+        assert_eq!(matcher.name_to_code(input), None, "{input}");
+    }
+    for demonym in ["American", "British", "german", "Samoan", "Emirati"] {
+        assert_eq!(matcher.name_to_code(demonym), None, "{demonym}");
+        assert_eq!(
+            matcher.try_name_to_code(demonym),
+            Err(LookupError::NotFound),
+            "{demonym}"
+        );
+    }
+    assert_eq!(matcher.name_to_code("American Samoa"), Some("AS"));
+    assert_eq!(matcher.name_to_code("United States"), Some("US"));
+    assert_eq!(matcher.code("🇺🇸"), Some("US"));
+
+    let registry = CountryRegistry::new().with_matcher(matcher);
+    assert_eq!(registry.name_to_code("American"), None);
+    assert_eq!(CountryRegistry::new().name_to_code("American"), Some("US"));
+}
+
+#[test]
+fn test_endonyms() {
+    assert_eq!(code_to_endonyms("DE"), Some(&["Deutschland"][..]));
+    assert_eq!(code_to_endonyms("fi"), Some(&["Suomi", "Finland"][..]));
+    assert_eq!(
+        code_to_endonyms(CountryCode::JP),
+        Some(&["日本", "Nihon", "Nippon"][..])
+    );
+    assert_eq!(code_to_endonyms("ZZ"), None);
+    assert_eq!(CountryCode::CH.country().endonyms()[0], "Schweiz");
+
+    for &code in CountryCode::ALL {
+        assert!(
+            !code.country().endonyms().is_empty(),
+            "{code} has no endonyms"
+        );
+    }
+
+    for (input, expected) in [
+        ("Deutschland", "DE"),
+        ("Nihon", "JP"),
+        ("日本", "JP"),
+        ("Suomi", "FI"),
+        ("Россия", "RU"),
+        ("Rossiya", "RU"),
+        ("대한민국", "KR"),
+        ("Sverige", "SE"),
+        ("Österreich", "AT"),
+        ("Osterreich", "AT"),
+        ("Magyarország", "HU"),
+        ("Hrvatska", "HR"),
+        ("Ísland", "IS"),
+        ("Éire", "IE"),
+        ("Aotearoa", "NZ"),
+        ("Bhārat", "IN"),
+        ("Bharat", "IN"),
+        ("Sāmoa", "WS"),
+    ] {
+        assert_eq!(code(input), Some(expected), "{input}");
+    }
+
+    // Endonyms never shadow English names or make ambiguous names resolve.
+    assert_eq!(code("Finland"), Some("FI"));
+    assert_eq!(code("Island"), None);
+    assert_eq!(code("Korea"), None);
+    assert_eq!(code("Congo"), None);
+}